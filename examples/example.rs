@@ -22,7 +22,6 @@ enum Update {
 #[must_use]
 struct App<'a> {
     data: &'a [u8],
-    last_area: Rect,
     render_times: Vec<Duration>,
     state: BinaryDataWidgetState,
 }
@@ -31,12 +30,6 @@ impl<'a> App<'a> {
     const fn new(data: &'a [u8]) -> Self {
         Self {
             data,
-            last_area: Rect {
-                x: 0,
-                y: 0,
-                width: 0,
-                height: 0,
-            },
             render_times: Vec::new(),
             state: BinaryDataWidgetState::new(),
         }
@@ -49,10 +42,10 @@ impl<'a> App<'a> {
                 KeyCode::Char('q') => return Update::Quit,
                 KeyCode::Esc => self.state.select_address(None),
                 KeyCode::Home if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                    self.state.select_address(Some(0))
+                    self.state.select_first()
                 }
                 KeyCode::End if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                    self.state.select_address(Some(usize::MAX))
+                    self.state.select_last(self.data.len())
                 }
                 KeyCode::Home => self.state.select_first_in_row(),
                 KeyCode::End => self.state.select_last_in_row(),
@@ -60,14 +53,34 @@ impl<'a> App<'a> {
                 KeyCode::Right => self.state.key_right(),
                 KeyCode::Down => self.state.key_down(),
                 KeyCode::Up => self.state.key_up(),
-                KeyCode::PageDown => self.state.scroll_down((self.last_area.height / 2) as usize),
-                KeyCode::PageUp => self.state.scroll_up((self.last_area.height / 2) as usize),
+                KeyCode::PageDown => {
+                    let before = self.state.selected_address();
+                    Some(self.state.half_page_down()) != before
+                }
+                KeyCode::PageUp => {
+                    let before = self.state.selected_address();
+                    Some(self.state.half_page_up()) != before
+                }
                 _ => return Update::Skip,
             },
             Event::Mouse(event) => match event.kind {
                 MouseEventKind::ScrollDown => self.state.scroll_down(1),
                 MouseEventKind::ScrollUp => self.state.scroll_up(1),
-                MouseEventKind::Down(_) => self.state.select_at(event.column, event.row),
+                MouseEventKind::Down(_) if event.modifiers.contains(KeyModifiers::CONTROL) => {
+                    if let Some(address) = self.state.clicked_address(event.column, event.row) {
+                        self.state.toggle_selected(address);
+                        true
+                    } else {
+                        false
+                    }
+                }
+                MouseEventKind::Down(_) => {
+                    self.state.clear_anchor();
+                    let cleared = self.state.clear_selection_set();
+                    self.state.select_at(event.column, event.row) || cleared
+                }
+                MouseEventKind::Drag(_) => self.state.drag_to(event.column, event.row),
+                MouseEventKind::Moved => self.state.hover_at(event.column, event.row),
                 _ => return Update::Skip,
             },
             Event::Resize(_, _) => return Update::Redraw,
@@ -82,7 +95,6 @@ impl<'a> App<'a> {
 
     fn draw(&mut self, frame: &mut Frame) {
         let area = frame.size();
-        self.last_area = area;
         let widget = BinaryDataWidget::new(self.data)
             .block(Block::bordered().title("Binary Data Widget"))
             .highlight_style(
@@ -90,7 +102,8 @@ impl<'a> App<'a> {
                     .fg(Color::Black)
                     .bg(Color::LightGreen)
                     .add_modifier(Modifier::BOLD),
-            );
+            )
+            .hover_style(Style::new().add_modifier(Modifier::REVERSED));
         let instant = Instant::now();
         frame.render_stateful_widget(widget, area, &mut self.state);
         self.render_times.push(instant.elapsed());
@@ -124,7 +137,7 @@ fn main() -> Result<(), Box<dyn Error>> {
         .next_back()
         .expect("The executable itself should always be an argument");
     let path = Path::new(&path);
-    println!("Read file: {path:?}");
+    println!("Read file: {}", path.display());
     let data = fs::read(path).expect("should be able to read the file");
     println!("Success. Show terminal ui.");
 
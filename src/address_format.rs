@@ -0,0 +1,22 @@
+/// Number system used to display the addresses of the [`BinaryDataWidget`](crate::BinaryDataWidget).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AddressFormat {
+    /// Hexadecimal addresses like `ff`
+    #[default]
+    Hex,
+    /// Decimal addresses like `255`
+    Decimal,
+    /// Octal addresses like `377`
+    Octal,
+}
+
+impl AddressFormat {
+    #[must_use]
+    pub(crate) const fn radix(self) -> u16 {
+        match self {
+            Self::Hex => 16,
+            Self::Decimal => 10,
+            Self::Octal => 8,
+        }
+    }
+}
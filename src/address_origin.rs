@@ -0,0 +1,11 @@
+/// Origin of the addresses displayed by the [`BinaryDataWidget`](crate::BinaryDataWidget).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AddressOrigin {
+    /// Addresses from the start of the data like `0x10`
+    #[default]
+    Absolute,
+    /// Signed distance of each row to the selected address like `+4` or `-8`.
+    ///
+    /// Without a selection the absolute addresses are shown.
+    RelativeToSelection,
+}
@@ -0,0 +1,21 @@
+/// Side of the [`BinaryDataWidget`](crate::BinaryDataWidget) the address column is shown on.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AddressPosition {
+    /// Addresses in front of the data
+    #[default]
+    Left,
+    /// Addresses after the data
+    Right,
+    /// Addresses in front of and after the data
+    Both,
+}
+
+impl AddressPosition {
+    pub(crate) const fn left(self) -> bool {
+        matches!(self, Self::Left | Self::Both)
+    }
+
+    pub(crate) const fn right(self) -> bool {
+        matches!(self, Self::Right | Self::Both)
+    }
+}
@@ -0,0 +1,20 @@
+/// Format of the byte values in the value column of the [`BinaryDataWidget`](crate::BinaryDataWidget).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ByteFormat {
+    /// Two hexadecimal digits per byte like `b4`
+    #[default]
+    Hex,
+    /// Eight bits per byte like `10110100`
+    Binary,
+}
+
+impl ByteFormat {
+    /// Amount of columns a single byte takes
+    #[must_use]
+    pub(crate) const fn width(self) -> u16 {
+        match self {
+            Self::Hex => 2,
+            Self::Binary => 8,
+        }
+    }
+}
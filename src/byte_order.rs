@@ -0,0 +1,11 @@
+/// Order of the bytes within a group in the hex column of the [`BinaryDataWidget`](crate::BinaryDataWidget).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ByteOrder {
+    /// Bytes are shown in the order of their addresses
+    #[default]
+    Native,
+    /// Bytes within each group are shown in reversed order.
+    ///
+    /// With a group size of 4 a little-endian 32-bit word reads in its natural order.
+    ReversedGroups,
+}
@@ -0,0 +1,27 @@
+use std::fmt;
+use std::rc::Rc;
+
+/// Clonable wrapper around user provided closures.
+///
+/// Closures do not implement [`Debug`] which is required for the widget.
+pub struct Callback<T: ?Sized>(pub Rc<T>);
+
+impl<T: ?Sized> core::ops::Deref for Callback<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> Clone for Callback<T> {
+    fn clone(&self) -> Self {
+        Self(Rc::clone(&self.0))
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for Callback<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Callback")
+    }
+}
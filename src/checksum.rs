@@ -0,0 +1,32 @@
+use crate::DataSource;
+
+/// CRC-32 (ISO-HDLC) as used by zip, png and `crc32` of zlib
+pub fn crc32<D: DataSource + ?Sized>(data: &D) -> u32 {
+    let mut crc = u32::MAX;
+    for address in 0..data.len() {
+        let Some(value) = data.byte(address) else {
+            break;
+        };
+        crc ^= u32::from(value);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_value() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(crc32(&[] as &[u8]), 0);
+    }
+}
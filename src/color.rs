@@ -1,21 +1,129 @@
 use ratatui::style::{Color, Modifier, Style};
 
+/// Styles of the different kinds of bytes.
+///
+/// The default matches [`color()`].
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColorScheme {
+    /// Style of `0x00`
+    pub null: Style,
+    /// Style of `0xff`
+    pub max: Style,
+    /// Style of ASCII whitespace like space, tab or newline
+    pub whitespace: Style,
+    /// Style of printable ASCII characters
+    pub graphic: Style,
+    /// Style of ASCII control characters
+    pub control: Style,
+    /// Style of all the other bytes
+    pub other: Style,
+}
+
+impl ColorScheme {
+    /// Same as [`ColorScheme::dark`].
+    pub const fn new() -> Self {
+        Self::dark()
+    }
+
+    /// Colors for terminals with a dark background like [`color()`].
+    pub const fn dark() -> Self {
+        Self {
+            null: Style::new().fg(Color::DarkGray),
+            max: Style::new().fg(Color::Blue).add_modifier(Modifier::BOLD),
+            whitespace: Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            graphic: Style::new()
+                .fg(Color::LightGreen)
+                .add_modifier(Modifier::BOLD),
+            control: Style::new().fg(Color::Red).add_modifier(Modifier::BOLD),
+            other: Style::new(),
+        }
+    }
+
+    /// Colors for terminals with a light background.
+    pub const fn light() -> Self {
+        Self {
+            null: Style::new().fg(Color::Gray),
+            max: Style::new().fg(Color::Blue).add_modifier(Modifier::BOLD),
+            whitespace: Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            graphic: Style::new().fg(Color::Green).add_modifier(Modifier::BOLD),
+            control: Style::new().fg(Color::Magenta).add_modifier(Modifier::BOLD),
+            other: Style::new(),
+        }
+    }
+
+    /// No colors at all, only modifiers distinguish the kinds of bytes.
+    ///
+    /// Useful for screenshots and terminals without colors.
+    pub const fn monochrome() -> Self {
+        Self {
+            null: Style::new().add_modifier(Modifier::DIM),
+            max: Style::new().add_modifier(Modifier::ITALIC),
+            whitespace: Style::new().add_modifier(Modifier::UNDERLINED),
+            graphic: Style::new().add_modifier(Modifier::BOLD),
+            control: Style::new().add_modifier(Modifier::REVERSED),
+            other: Style::new(),
+        }
+    }
+
+    /// Returns a [`Style`] which is used to style the given `character` on render.
+    #[must_use]
+    pub const fn style(&self, character: char) -> Style {
+        if character as u8 == 0 {
+            self.null
+        } else if character as u8 == 0xff {
+            self.max
+        } else if character.is_ascii_whitespace() {
+            self.whitespace
+        } else if character.is_ascii_graphic() {
+            self.graphic
+        } else if character.is_ascii_control() {
+            self.control
+        } else {
+            self.other
+        }
+    }
+}
+
+impl Default for ColorScheme {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Returns a [`Style`] which is used to style the given `character` on render.
 #[must_use]
 pub const fn color(character: char) -> Style {
-    if character as u8 == 0 {
-        Style::new().fg(Color::DarkGray)
-    } else if character as u8 == 0xff {
-        Style::new().fg(Color::Blue).add_modifier(Modifier::BOLD)
-    } else if character.is_ascii_whitespace() {
-        Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD)
-    } else if character.is_ascii_graphic() {
-        Style::new()
-            .fg(Color::LightGreen)
-            .add_modifier(Modifier::BOLD)
-    } else if character.is_ascii_control() {
-        Style::new().fg(Color::Red).add_modifier(Modifier::BOLD)
-    } else {
-        Style::new()
+    ColorScheme::new().style(character)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dark_is_default() {
+        assert_eq!(ColorScheme::default(), ColorScheme::dark());
+        assert_eq!(ColorScheme::dark().style('\x01'), color('\x01'));
+    }
+
+    #[test]
+    fn light_differs_from_dark() {
+        assert_ne!(
+            ColorScheme::light().style('\x01'),
+            ColorScheme::dark().style('\x01')
+        );
+    }
+
+    #[test]
+    fn monochrome_has_no_colors() {
+        let scheme = ColorScheme::monochrome();
+        for value in 0..=u8::MAX {
+            let style = scheme.style(value as char);
+            assert_eq!(style.fg, None);
+            assert_eq!(style.bg, None);
+        }
+        assert_ne!(scheme.style('\x01'), scheme.style('a'));
+        assert_ne!(scheme.style('\0'), scheme.style('\u{ff}'));
     }
 }
@@ -0,0 +1,140 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
+/// Source of the bytes shown by the [`BinaryDataWidget`](crate::BinaryDataWidget).
+///
+/// Mostly the bytes currently visible are requested on render, see [`BinaryDataWidget::visible_byte_range`](crate::BinaryDataWidget::visible_byte_range).
+/// Some options request bytes outside of the view too: the minimap, the string and search highlights, the detail line and the checksum footer.
+/// This allows for lazy sources like memory mapped or seek on demand files.
+pub trait DataSource {
+    /// Amount of bytes in the source
+    fn len(&self) -> usize;
+
+    /// Returns the byte at the given address.
+    /// Returns `None` when the address is out of range.
+    fn byte(&self, address: usize) -> Option<u8>;
+
+    /// Returns `true` when the source contains no bytes.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns all bytes at once when they are in memory.
+    ///
+    /// Their location allows to cache calculations over the whole data across renders.
+    fn as_slice(&self) -> Option<&[u8]> {
+        None
+    }
+}
+
+impl DataSource for [u8] {
+    fn len(&self) -> usize {
+        <[u8]>::len(self)
+    }
+
+    fn byte(&self, address: usize) -> Option<u8> {
+        self.get(address).copied()
+    }
+
+    fn as_slice(&self) -> Option<&[u8]> {
+        Some(self)
+    }
+}
+
+impl<const N: usize> DataSource for [u8; N] {
+    fn len(&self) -> usize {
+        N
+    }
+
+    fn byte(&self, address: usize) -> Option<u8> {
+        self.get(address).copied()
+    }
+
+    fn as_slice(&self) -> Option<&[u8]> {
+        Some(self)
+    }
+}
+
+impl DataSource for Vec<u8> {
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    fn byte(&self, address: usize) -> Option<u8> {
+        self.get(address).copied()
+    }
+
+    fn as_slice(&self) -> Option<&[u8]> {
+        Some(self)
+    }
+}
+
+impl DataSource for Cow<'_, [u8]> {
+    fn len(&self) -> usize {
+        self.as_ref().len()
+    }
+
+    fn byte(&self, address: usize) -> Option<u8> {
+        self.get(address).copied()
+    }
+
+    fn as_slice(&self) -> Option<&[u8]> {
+        Some(self)
+    }
+}
+
+impl<T: DataSource + ?Sized> DataSource for &T {
+    fn len(&self) -> usize {
+        T::len(self)
+    }
+
+    fn byte(&self, address: usize) -> Option<u8> {
+        T::byte(self, address)
+    }
+
+    fn as_slice(&self) -> Option<&[u8]> {
+        T::as_slice(self)
+    }
+}
+
+/// Part of a [`DataSource`] whose addresses start at the beginning of the `range`.
+pub struct DataWindow<'s, D: ?Sized> {
+    data: &'s D,
+    range: Range<usize>,
+}
+
+impl<'s, D: DataSource + ?Sized> DataWindow<'s, D> {
+    /// The `range` is clamped to the bytes of the `data`.
+    pub(crate) fn new(data: &'s D, range: Option<Range<usize>>) -> Self {
+        let len = data.len();
+        let range = range.map_or(0..len, |range| {
+            let start = range.start.min(len);
+            start..range.end.clamp(start, len)
+        });
+        Self { data, range }
+    }
+
+    /// Address of the first byte of the window within the `data`.
+    pub(crate) const fn start(&self) -> usize {
+        self.range.start
+    }
+}
+
+impl<D: DataSource + ?Sized> DataSource for DataWindow<'_, D> {
+    fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    fn byte(&self, address: usize) -> Option<u8> {
+        if address >= self.range.len() {
+            return None;
+        }
+        self.data.byte(self.range.start + address)
+    }
+
+    fn as_slice(&self) -> Option<&[u8]> {
+        self.data
+            .as_slice()
+            .and_then(|slice| slice.get(self.range.clone()))
+    }
+}
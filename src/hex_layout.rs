@@ -0,0 +1,39 @@
+/// Spacing of the columns of the [`BinaryDataWidget`](crate::BinaryDataWidget).
+///
+/// See [`BinaryDataWidget::layout`](crate::BinaryDataWidget::layout).
+/// The [`Default`] results in rows like `10: 6162 6364 abcd`.
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HexLayout {
+    /// Text after each address
+    pub address_suffix: &'static str,
+    /// Amount of columns between the groups of the hex column
+    pub group_gap: u16,
+    /// Amount of columns between the hex and the char column
+    pub char_gap: u16,
+    /// Amount of bytes grouped together in the hex column. A group size of `0` is treated as `1`.
+    pub group_size: u16,
+}
+
+impl HexLayout {
+    /// Layout with `": "` after the address, a gap of one column everywhere and 2 bytes per group.
+    pub const fn new() -> Self {
+        Self {
+            address_suffix: ": ",
+            group_gap: 1,
+            char_gap: 1,
+            group_size: 2,
+        }
+    }
+
+    /// Amount of columns the [`address_suffix`](Self::address_suffix) takes
+    pub(crate) fn address_suffix_width(&self) -> u16 {
+        u16::try_from(self.address_suffix.chars().count()).unwrap_or(u16::MAX)
+    }
+}
+
+impl Default for HexLayout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
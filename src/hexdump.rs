@@ -0,0 +1,93 @@
+use std::fmt::Write as _;
+
+/// Returns the full `data` as text in the canonical `xxd -g2` format.
+///
+/// In contrast to the widget this is independent of a terminal size.
+/// Every row ends with a newline.
+///
+/// A `bytes_per_row` of `0` is treated as `1`.
+///
+/// # Example
+///
+/// ```
+/// let dump = ratatui_binary_data_widget::to_hexdump(b"Hello world!\n", 8, false);
+/// assert_eq!(
+///     dump,
+///     "00000000: 4865 6c6c 6f20 776f  Hello wo\n00000008: 726c 6421 0a         rld!.\n"
+/// );
+/// ```
+#[must_use]
+pub fn to_hexdump(data: &[u8], bytes_per_row: usize, uppercase: bool) -> String {
+    let bytes_per_row = bytes_per_row.max(1);
+    let hex_width = bytes_per_row
+        .saturating_mul(2)
+        .saturating_add(bytes_per_row.div_ceil(2).saturating_sub(1));
+
+    let mut result = String::new();
+    for (row_index, row) in data.chunks(bytes_per_row).enumerate() {
+        let offset = row_index.saturating_mul(bytes_per_row);
+        if uppercase {
+            _ = write!(result, "{offset:08X}: ");
+        } else {
+            _ = write!(result, "{offset:08x}: ");
+        }
+
+        let mut hex = String::with_capacity(hex_width);
+        for (index, value) in row.iter().enumerate() {
+            if index > 0 && index % 2 == 0 {
+                hex.push(' ');
+            }
+            if uppercase {
+                _ = write!(hex, "{value:02X}");
+            } else {
+                _ = write!(hex, "{value:02x}");
+            }
+        }
+        _ = write!(result, "{hex:<hex_width$}  ");
+
+        for value in row {
+            let character = *value as char;
+            result.push(if character == ' ' || character.is_ascii_graphic() {
+                character
+            } else {
+                '.'
+            });
+        }
+        result.push('\n');
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xxd_partial_row() {
+        // printf 'Hello world!\n' | xxd -g2
+        let expected = "00000000: 4865 6c6c 6f20 776f 726c 6421 0a         Hello world!.\n";
+        assert_eq!(to_hexdump(b"Hello world!\n", 16, false), expected);
+    }
+
+    #[test]
+    fn xxd_multiple_rows() {
+        // printf 'Hello world!\nABC\x00\xff' | xxd -g2 -c8
+        let expected = "00000000: 4865 6c6c 6f20 776f  Hello wo
+00000008: 726c 6421 0a41 4243  rld!.ABC
+00000010: 00ff                 ..
+";
+        assert_eq!(to_hexdump(b"Hello world!\nABC\x00\xff", 8, false), expected);
+    }
+
+    #[test]
+    fn xxd_uppercase_odd_row() {
+        // printf 'Hello' | xxd -g2 -c3 -u
+        let expected = "00000000: 4865 6C  Hel\n00000003: 6C6F     lo\n";
+        assert_eq!(to_hexdump(b"Hello", 3, true), expected);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(to_hexdump(b"", 16, false), "");
+    }
+}
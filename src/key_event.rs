@@ -0,0 +1,130 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+use crate::BinaryDataWidgetState;
+
+impl BinaryDataWidgetState {
+    /// Handle a key press with the common bindings of the widget.
+    ///
+    /// - Arrow keys move the selection like [`key_up()`](Self::key_up) and the other arrow key methods
+    /// - Home and End select the first and last byte of the row
+    /// - Ctrl+Home and Ctrl+End select the first and last byte of the data of the last render
+    /// - Page Up and Page Down move the selection by `page_height` lines
+    /// - Esc removes the selection
+    ///
+    /// Key releases and other keys are ignored.
+    ///
+    /// Returns `true` when the state changed.
+    pub fn handle_key_event(&mut self, key: KeyEvent, page_height: usize) -> bool {
+        if key.kind == KeyEventKind::Release {
+            return false;
+        }
+        let control = key.modifiers.contains(KeyModifiers::CONTROL);
+        let page = page_height.saturating_mul(self.last_per_row());
+        match key.code {
+            KeyCode::Esc => self.select_address(None),
+            KeyCode::Home if control => self.select_first(),
+            KeyCode::End if control => self
+                .last_render_positions
+                .is_some_and(|positions| self.select_address(Some(positions.biggest_address))),
+            KeyCode::Home => self.select_first_in_row(),
+            KeyCode::End => self.select_last_in_row(),
+            KeyCode::Left => self.key_left(),
+            KeyCode::Right => self.key_right(),
+            KeyCode::Up => self.key_up(),
+            KeyCode::Down => self.key_down(),
+            KeyCode::PageUp => {
+                let before = self.selected_address;
+                Some(self.move_selection_up(page)) != before
+            }
+            KeyCode::PageDown => {
+                let before = self.selected_address;
+                Some(self.move_selection_down(page)) != before
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::StatefulWidget;
+
+    use super::*;
+    use crate::BinaryDataWidget;
+
+    fn rendered(data: &[u8]) -> BinaryDataWidgetState {
+        let mut state = BinaryDataWidgetState::new();
+        let area = Rect::new(0, 0, 19, 5);
+        let mut buffer = Buffer::empty(area);
+        StatefulWidget::render(BinaryDataWidget::new(data), area, &mut buffer, &mut state);
+        state
+    }
+
+    fn press(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn arrows() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let mut state = rendered(&data);
+        assert!(state.handle_key_event(press(KeyCode::Down), 5));
+        assert_eq!(state.selected_address(), Some(0));
+        assert!(state.handle_key_event(press(KeyCode::Down), 5));
+        assert_eq!(state.selected_address(), Some(4));
+        assert!(state.handle_key_event(press(KeyCode::Right), 5));
+        assert_eq!(state.selected_address(), Some(5));
+        assert!(state.handle_key_event(press(KeyCode::Left), 5));
+        assert!(state.handle_key_event(press(KeyCode::Up), 5));
+        assert_eq!(state.selected_address(), Some(0));
+    }
+
+    #[test]
+    fn home_and_end() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let mut state = rendered(&data);
+        state.select_address(Some(5));
+        assert!(state.handle_key_event(press(KeyCode::End), 5));
+        assert_eq!(state.selected_address(), Some(7));
+        assert!(state.handle_key_event(press(KeyCode::Home), 5));
+        assert_eq!(state.selected_address(), Some(4));
+        let control_end = KeyEvent::new(KeyCode::End, KeyModifiers::CONTROL);
+        assert!(state.handle_key_event(control_end, 5));
+        assert_eq!(state.selected_address(), Some(0x3f));
+        let control_home = KeyEvent::new(KeyCode::Home, KeyModifiers::CONTROL);
+        assert!(state.handle_key_event(control_home, 5));
+        assert_eq!(state.selected_address(), Some(0));
+    }
+
+    #[test]
+    fn page_up_and_down() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let mut state = rendered(&data);
+        state.select_address(Some(1));
+        assert!(state.handle_key_event(press(KeyCode::PageDown), 3));
+        assert_eq!(state.selected_address(), Some(13));
+        assert!(state.handle_key_event(press(KeyCode::PageUp), 3));
+        assert_eq!(state.selected_address(), Some(1));
+        assert!(state.handle_key_event(press(KeyCode::PageUp), 3));
+        assert_eq!(state.selected_address(), Some(0));
+        assert!(!state.handle_key_event(press(KeyCode::PageUp), 3));
+    }
+
+    #[test]
+    fn escape_and_ignored_keys() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let mut state = rendered(&data);
+        state.select_address(Some(1));
+        assert!(!state.handle_key_event(press(KeyCode::Char('x')), 5));
+        let release = KeyEvent {
+            kind: KeyEventKind::Release,
+            ..press(KeyCode::Esc)
+        };
+        assert!(!state.handle_key_event(release, 5));
+        assert_eq!(state.selected_address(), Some(1));
+        assert!(state.handle_key_event(press(KeyCode::Esc), 5));
+        assert_eq!(state.selected_address(), None);
+    }
+}
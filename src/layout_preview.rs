@@ -0,0 +1,65 @@
+use ratatui::layout::Rect;
+
+use crate::render_positions::RenderPositions;
+
+/// Positions of the columns the [`BinaryDataWidget`](crate::BinaryDataWidget) would render in an area.
+///
+/// Created with [`BinaryDataWidget::layout_preview`](crate::BinaryDataWidget::layout_preview) without rendering anything.
+/// All x positions are absolute terminal columns.
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LayoutPreview(RenderPositions);
+
+impl LayoutPreview {
+    pub(crate) const fn new(positions: RenderPositions) -> Self {
+        Self(positions)
+    }
+
+    /// Area the data is rendered in, without the block, header or footers
+    #[must_use]
+    pub const fn data_area(&self) -> Rect {
+        self.0.inner_area
+    }
+
+    /// Amount of addresses per row of data
+    #[must_use]
+    pub const fn per_row(&self) -> u16 {
+        self.0.per_row
+    }
+
+    /// Amount of addresses per row which fit into the area and are therefore rendered
+    #[must_use]
+    pub const fn visible_per_row(&self) -> u16 {
+        self.0.visible_per_row
+    }
+
+    /// Amount of digits of the address column
+    #[must_use]
+    pub const fn address_width(&self) -> u16 {
+        self.0.address_width
+    }
+
+    /// Amount of lines needed to show all of the data
+    #[must_use]
+    pub const fn total_lines(&self) -> usize {
+        self.0.available_data_lines
+    }
+
+    /// Column of the byte with the given index on the row in the hex column
+    #[must_use]
+    pub const fn x_hex(&self, index_on_row: u16) -> u16 {
+        self.0.x_hex(0, index_on_row)
+    }
+
+    /// Column of the byte with the given index on the row in the char column
+    #[must_use]
+    pub const fn x_char(&self, index_on_row: u16) -> u16 {
+        self.0.x_char(index_on_row)
+    }
+
+    /// Amount of columns a single byte takes in the hex column
+    #[must_use]
+    pub const fn byte_width(&self) -> u16 {
+        self.0.byte_width
+    }
+}
@@ -3,25 +3,67 @@ Widget built to show binary data.
 
 The main struct is the [`BinaryDataWidget`].
 The user interaction state (like the current selection) is stored in the [`BinaryDataWidgetState`].
+Besides slices the widget can show any [`DataSource`] like lazily loaded files.
 
 For the used colors see the source code of [`color()`].
+They can be changed with a [`ColorScheme`].
 */
 
-use ratatui::buffer::Buffer;
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+use std::io::{self, Read};
+use std::ops::{Range, RangeInclusive};
+use std::rc::Rc;
+
+use ratatui::buffer::{Buffer, Cell};
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Style};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
 use ratatui::widgets::block::BlockExt as _;
 use ratatui::widgets::{
     Block, Scrollbar, ScrollbarOrientation, ScrollbarState, StatefulWidget, Widget,
 };
 
-pub use self::color::color;
-use self::render_positions::RenderPositions;
+pub use self::address_format::AddressFormat;
+pub use self::address_origin::AddressOrigin;
+pub use self::address_position::AddressPosition;
+pub use self::byte_format::ByteFormat;
+pub use self::byte_order::ByteOrder;
+use self::callback::Callback;
+pub use self::color::{color, ColorScheme};
+pub use self::data_source::DataSource;
+use self::data_source::DataWindow;
+pub use self::hex_layout::HexLayout;
+pub use self::hexdump::to_hexdump;
+pub use self::layout_preview::LayoutPreview;
+pub use self::nav_outcome::NavOutcome;
+pub use self::nibble::Nibble;
+pub use self::region::Region;
+pub use self::render_positions::lines_needed;
+use self::render_positions::{LayoutOptions, RenderPositions};
 pub use self::state::State as BinaryDataWidgetState;
+pub use self::view_token::ViewToken;
 
+mod address_format;
+mod address_origin;
+mod address_position;
+mod byte_format;
+mod byte_order;
+mod callback;
+mod checksum;
 mod color;
+mod data_source;
+mod hex_layout;
+mod hexdump;
+#[cfg(feature = "crossterm")]
+mod key_event;
+mod layout_preview;
+mod nav_outcome;
+mod nibble;
+mod region;
 mod render_positions;
 mod state;
+mod view_token;
 
 /// A widget to render binary data.
 //
@@ -47,25 +89,222 @@ mod state;
 /// ```
 #[must_use = "The widget is only useful when rendered"]
 #[derive(Debug, Clone)]
-pub struct BinaryDataWidget<'a> {
-    data: &'a [u8],
+#[allow(clippy::struct_excessive_bools)]
+pub struct BinaryDataWidget<'a, D = Cow<'a, [u8]>> {
+    data: D,
+    /// Part of the data which is shown, `None` shows everything
+    data_range: Option<Range<usize>>,
 
     block: Option<Block<'a>>,
+    /// Vertical scrollbar on the right, `None` disables it
+    scrollbar: Option<Scrollbar<'a>>,
+    /// Horizontal scrollbar at the bottom when the rows do not fit, `None` disables it
+    horizontal_scrollbar: Option<Scrollbar<'a>>,
     /// Style used as a base style for the widget
     style: Style,
 
+    /// Style used to render the addresses and the header
+    address_style: Style,
+
     /// Style used to render selected item
     highlight_style: Style,
+
+    /// Style of the selected address itself, the moving end of a selected range
+    cursor_style: Option<Style>,
+
+    /// Style patched onto every other row
+    row_stripe_style: Option<Style>,
+
+    /// Style patched onto the row and column of the selected item
+    crosshair_style: Option<Style>,
+
+    /// Style of the item under the mouse
+    hover_style: Option<Style>,
+
+    /// Dim the bytes outside of a selected range
+    dim_unselected: bool,
+
+    /// Options influencing the positions of the rendered content
+    layout: LayoutOptions,
+
+    /// Render hex digits of addresses and values in uppercase
+    uppercase: bool,
+
+    /// Render a header row with the index of each column
+    show_header: bool,
+
+    /// Hint at the area being too small when no bytes fit
+    show_too_small_hint: bool,
+
+    /// Render a footer row with the CRC32 of the data
+    show_checksum: bool,
+
+    /// Render a footer row with the value of the selected byte
+    show_detail_line: bool,
+
+    /// Render the density of printable bytes of the whole data on the right
+    show_minimap: bool,
+
+    /// Style used to render bookmarked addresses
+    bookmark_style: Option<Style>,
+
+    /// Annotated spans with their own style
+    regions: &'a [Region],
+
+    /// Modified addresses are styled with the `modified_style`
+    modified: Option<&'a BTreeSet<usize>>,
+    modified_style: Style,
+
+    /// Data to compare with, differing bytes are styled with the `diff_style`
+    diff_against: Option<&'a [u8]>,
+    diff_style: Style,
+
+    /// Every occurrence of the needle is styled with the `search_style`, empty disables it
+    search_needle: &'a [u8],
+    search_style: Style,
+
+    /// Runs of at least this many printable bytes are styled with the `string_style`, 0 disables it
+    min_string_len: usize,
+    string_style: Style,
+
+    /// Message shown instead of the data when there is nothing to show
+    empty_message: Option<&'a str>,
+
+    /// Symbol in the char column for bytes which are not printable
+    non_printable_symbol: char,
+
+    /// Symbol in the char column for the selected address instead of its byte
+    selected_char_override: Option<char>,
+
+    /// Show control bytes as Unicode control pictures like `␊` in the char column
+    control_pictures: bool,
+
+    /// Symbol filling the cells after the end of the data on the last row
+    pad_last_row_symbol: Option<char>,
+
+    /// Overwrite the cells of the char column after the end of the data on the last row with spaces
+    pad_char_column: bool,
+
+    /// Symbol in the char column for each byte value
+    char_map: Option<&'a [char; 256]>,
+
+    /// Symbol between the groups of the hex column
+    group_separator: char,
+
+    /// Symbol in the middle of the gap between the hex and the char column
+    hex_char_separator: Option<char>,
+
+    /// Draw a vertical rule in the group gap every this many bytes
+    column_rule_every: Option<u16>,
+    column_rule_style: Style,
+
+    /// Styles of the different kinds of bytes
+    color_scheme: ColorScheme,
+    /// Style of `0x00` instead of the one of the [`ColorScheme`]
+    null_byte_style: Option<Style>,
+    /// Style of `0xff` instead of the one of the [`ColorScheme`]
+    fill_byte_style: Option<Style>,
+
+    /// Style of each byte instead of the [`ColorScheme`]
+    byte_style_fn: Option<Callback<dyn Fn(usize, u8) -> Style + 'a>>,
+
+    /// Marker of each row in the gutter between the addresses and the data
+    row_marker_fn: Option<Callback<RowMarkerFn<'a>>>,
 }
 
+type RowMarkerFn<'a> = dyn Fn(usize) -> Option<(char, Style)> + 'a;
+
 impl<'a> BinaryDataWidget<'a> {
     /// Create a new `BinaryDataWidget`.
     pub const fn new(data: &'a [u8]) -> Self {
+        Self::from_source(Cow::Borrowed(data))
+    }
+
+    /// Create a new `BinaryDataWidget` owning its data.
+    ///
+    /// Useful when the data is only created for the current frame.
+    pub const fn from_owned(data: Vec<u8>) -> Self {
+        Self::from_source(Cow::Owned(data))
+    }
+
+    /// Create a new `BinaryDataWidget` owning the data read from the `reader`.
+    ///
+    /// At most `max_bytes` are read which guards against endless sources like pipes.
+    /// Everything after is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the `reader` when reading failed.
+    pub fn from_reader<R: Read>(reader: R, max_bytes: usize) -> io::Result<Self> {
+        let mut data = Vec::new();
+        let limit = u64::try_from(max_bytes).unwrap_or(u64::MAX);
+        reader.take(limit).read_to_end(&mut data)?;
+        Ok(Self::from_owned(data))
+    }
+}
+
+impl<'a, D> BinaryDataWidget<'a, D> {
+    /// Create a new `BinaryDataWidget` showing the bytes of any [`DataSource`].
+    pub const fn from_source(data: D) -> Self {
         Self {
             data,
+            data_range: None,
             block: None,
+            scrollbar: Some(
+                Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                    .begin_symbol(None)
+                    .track_symbol(None)
+                    .end_symbol(None),
+            ),
+            horizontal_scrollbar: Some(
+                Scrollbar::new(ScrollbarOrientation::HorizontalBottom)
+                    .begin_symbol(None)
+                    .track_symbol(None)
+                    .end_symbol(None),
+            ),
             style: Style::new(),
+            address_style: Style::new().fg(Color::Cyan),
             highlight_style: Style::new(),
+            cursor_style: None,
+            row_stripe_style: None,
+            crosshair_style: None,
+            hover_style: None,
+            dim_unselected: false,
+            layout: LayoutOptions::new(),
+            uppercase: false,
+            show_header: false,
+            show_too_small_hint: false,
+            show_checksum: false,
+            show_detail_line: false,
+            show_minimap: false,
+            bookmark_style: None,
+            regions: &[],
+            modified: None,
+            modified_style: Style::new()
+                .fg(Color::LightRed)
+                .add_modifier(Modifier::BOLD),
+            diff_against: None,
+            diff_style: Style::new().add_modifier(Modifier::REVERSED),
+            search_needle: &[],
+            search_style: Style::new().fg(Color::Black).bg(Color::Yellow),
+            min_string_len: 0,
+            string_style: Style::new().add_modifier(Modifier::UNDERLINED),
+            empty_message: None,
+            non_printable_symbol: '·',
+            selected_char_override: None,
+            control_pictures: false,
+            pad_last_row_symbol: None,
+            pad_char_column: false,
+            char_map: None,
+            group_separator: ' ',
+            hex_char_separator: None,
+            column_rule_every: None,
+            column_rule_style: Style::new().fg(Color::DarkGray),
+            color_scheme: ColorScheme::new(),
+            null_byte_style: None,
+            fill_byte_style: None,
+            byte_style_fn: None,
+            row_marker_fn: None,
         }
     }
 
@@ -75,225 +314,3179 @@ impl<'a> BinaryDataWidget<'a> {
         self
     }
 
+    /// Scrollbar rendered on the right or `None` to hide it.
+    ///
+    /// When there is a border to the right it is rendered on top of it.
+    /// Defaults to a minimal scrollbar showing only the thumb.
+    pub const fn scrollbar(mut self, scrollbar: Option<Scrollbar<'a>>) -> Self {
+        self.scrollbar = scrollbar;
+        self
+    }
+
+    /// Scrollbar rendered at the bottom when the [`bytes_per_row`](Self::bytes_per_row) do not fit or `None` to hide it.
+    ///
+    /// It takes the last row of the area only while it is needed.
+    /// Defaults to a minimal scrollbar showing only the thumb.
+    pub const fn horizontal_scrollbar(mut self, scrollbar: Option<Scrollbar<'a>>) -> Self {
+        self.horizontal_scrollbar = scrollbar;
+        self
+    }
+
     pub const fn style(mut self, style: Style) -> Self {
         self.style = style;
         self
     }
 
+    /// Style of the address column and the header.
+    ///
+    /// It is patched onto the base [`style`](Self::style).
+    /// Defaults to cyan.
+    pub const fn address_style(mut self, style: Style) -> Self {
+        self.address_style = style;
+        self
+    }
+
     pub const fn highlight_style(mut self, style: Style) -> Self {
         self.highlight_style = style;
         self
     }
 
-    /// Returns the amount of lines that could be written with the given area width.
+    /// Style of the selected address which differs from the rest of the selected range.
     ///
-    /// With this information the height of the resulting widget can be limited.
-    #[must_use]
-    pub fn get_max_lines_of_data_in_area(&self, area: Rect) -> usize {
-        let inner = self.block.inner_if_some(area);
-        RenderPositions::new(inner, self.data.len())
-            .map_or(0, |positions| positions.available_data_lines)
+    /// Defaults to the [`highlight_style`](Self::highlight_style).
+    pub const fn cursor_style(mut self, style: Style) -> Self {
+        self.cursor_style = Some(style);
+        self
     }
-}
 
-impl StatefulWidget for BinaryDataWidget<'_> {
-    type State = BinaryDataWidgetState;
+    /// Patch the style onto every other row of data for easier scanning.
+    ///
+    /// The stripe is below the styles of the addresses and bytes.
+    /// Defaults to no stripes.
+    pub const fn row_stripe_style(mut self, style: Style) -> Self {
+        self.row_stripe_style = Some(style);
+        self
+    }
 
-    #[allow(clippy::too_many_lines)]
-    fn render(self, full_area: Rect, buffer: &mut Buffer, state: &mut Self::State) {
-        buffer.set_style(full_area, self.style);
+    /// Patch the style of all bytes in the row and the column of the selected byte.
+    ///
+    /// The selected byte itself keeps the [`highlight_style`](Self::highlight_style).
+    /// Defaults to no crosshair.
+    pub const fn crosshair_style(mut self, style: Style) -> Self {
+        self.crosshair_style = Some(style);
+        self
+    }
 
-        // Get the inner area inside a possible block, otherwise use the full area
-        let area = self.block.map_or(full_area, |block| {
-            let inner_area = block.inner(full_area);
-            block.render(full_area, buffer);
-            inner_area
-        });
+    /// Style of the byte under the mouse, see [`BinaryDataWidgetState::hover_at`].
+    ///
+    /// Selected bytes keep the [`highlight_style`](Self::highlight_style).
+    /// Defaults to no hover highlight.
+    pub const fn hover_style(mut self, style: Style) -> Self {
+        self.hover_style = Some(style);
+        self
+    }
 
-        state.last_render_positions = RenderPositions::new(area, self.data.len());
-        let Some(positions) = state.last_render_positions else {
-            return;
+    /// Add [`Modifier::DIM`] to every byte outside of the [`selected_range`](BinaryDataWidgetState::selected_range) and the [`selection_set`](BinaryDataWidgetState::selection_set).
+    ///
+    /// Without a selected range or selection set nothing is dimmed.
+    /// Defaults to `false`.
+    pub const fn dim_unselected(mut self, dim_unselected: bool) -> Self {
+        self.dim_unselected = dim_unselected;
+        self
+    }
+
+    /// Show exactly the given amount of bytes per row.
+    ///
+    /// By default the amount of bytes per row is the biggest power of two fitting the area.
+    /// With a fixed amount the rows are truncated when the area is too narrow.
+    /// Setting `0` returns to the automatic calculation.
+    pub const fn bytes_per_row(mut self, bytes_per_row: u16) -> Self {
+        self.layout.bytes_per_row = if bytes_per_row == 0 {
+            None
+        } else {
+            Some(bytes_per_row)
         };
-        let RenderPositions {
-            address_width,
-            per_row,
-            available_data_lines,
-            ..
-        } = positions;
+        self
+    }
 
-        // Ensure offset is actually in data range
-        state.offset_address = state.offset_address.min(self.data.len().saturating_sub(1));
-        // Ensure selected_address is actually selectable
-        if let Some(selected) = state.selected_address {
-            state.selected_address = Some(self.data.len().saturating_sub(1).min(selected));
-        }
+    /// Render the hex digits of the address and value columns in uppercase.
+    ///
+    /// Defaults to lowercase.
+    pub const fn uppercase(mut self, uppercase: bool) -> Self {
+        self.uppercase = uppercase;
+        self
+    }
 
-        let available_height = area.height as usize;
+    /// Number system used to display the addresses.
+    ///
+    /// Defaults to [`AddressFormat::Hex`].
+    pub const fn address_format(mut self, address_format: AddressFormat) -> Self {
+        self.layout.address_format = address_format;
+        self
+    }
 
-        let mut start_line = state.offset_address.saturating_div(per_row as usize);
-        if state.ensure_selected_in_view_on_next_render {
-            if let Some(selected_address) = state.selected_address {
-                let selected_line = selected_address.saturating_div(per_row as usize);
-                if selected_line < start_line {
-                    // Move offset up
-                    start_line = selected_line;
-                } else {
-                    let end_line = start_line.saturating_add(available_height);
-                    if selected_line >= end_line {
-                        // Move offset down
-                        let end_line = selected_line.saturating_add(1);
-                        start_line = end_line.saturating_sub(available_height);
-                    }
-                }
-            }
-            state.offset_address = start_line.saturating_mul(per_row as usize);
-            state.ensure_selected_in_view_on_next_render = false;
-        }
+    /// Show the addresses from the start of the data or relative to the selection.
+    ///
+    /// Relative addresses get a sign column in front.
+    /// Defaults to [`AddressOrigin::Absolute`].
+    pub const fn address_origin(mut self, address_origin: AddressOrigin) -> Self {
+        self.layout.address_origin = address_origin;
+        self
+    }
 
-        let visible_lines = available_data_lines
-            .saturating_sub(start_line)
-            .min(available_height);
+    /// Show the addresses in front of the data, after the data or on both sides.
+    ///
+    /// The addresses after the data keep a column free for the scrollbar.
+    /// Defaults to [`AddressPosition::Left`].
+    pub const fn address_position(mut self, address_position: AddressPosition) -> Self {
+        self.layout.address_position = address_position;
+        self
+    }
 
-        {
-            // Render Scrollbar
-            // When there is a border to the right it is rendered on top.
-            // -> Scrollbar and data always visible
-            // When there is no border it is still rendered before the binary data
-            // -> the scrollbar might not be visible but the data always is
-            let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
-                .begin_symbol(None)
-                .track_symbol(None)
-                .end_symbol(None);
-            let overscroll_workaround = available_data_lines.saturating_sub(available_height);
-            let mut scrollbar_state = ScrollbarState::new(overscroll_workaround)
-                .position(start_line)
-                // Should be available_height but with the current overscroll workaround this looks nicer
-                .viewport_content_length(visible_lines);
-            let scrollbar_area = Rect {
-                // Inner height to be exactly as the content
-                y: area.y,
-                height: area.height,
-                // Outer width to stay on the right border
-                x: full_area.x,
-                width: full_area.width,
-            };
-            scrollbar.render(scrollbar_area, buffer, &mut scrollbar_state);
-        }
+    /// Render the hex column with the values of the bytes.
+    ///
+    /// Hiding it results in a text view with only the char column which allows for way more bytes per row.
+    /// Defaults to `true`.
+    pub const fn show_hex_column(mut self, show: bool) -> Self {
+        self.layout.show_hex_column = show;
+        self
+    }
 
-        let address_width = address_width as usize;
-        #[allow(clippy::cast_possible_truncation)]
-        let visible_lines = visible_lines as u16;
-        let x = area.left();
+    /// Render the char column next to the hex values.
+    ///
+    /// Hiding it allows for more bytes per row.
+    /// Defaults to `true`.
+    pub const fn show_char_column(mut self, show: bool) -> Self {
+        self.layout.show_char_column = show;
+        self
+    }
 
-        for line_index in 0..visible_lines {
-            const ADDRESS_STYLE: Style = Style::new().fg(Color::Cyan);
+    /// Style used to render the bookmarks of the [`BinaryDataWidgetState`].
+    ///
+    /// The selection highlight takes precedence over the bookmark style.
+    /// Without a bookmark style bookmarks are not shown.
+    pub const fn bookmark_style(mut self, style: Style) -> Self {
+        self.bookmark_style = Some(style);
+        self
+    }
 
-            let y = area.top().saturating_add(line_index);
+    /// Render the given addresses with the [`modified_style`](Self::modified_style).
+    ///
+    /// Useful for hex editors to show which bytes were changed.
+    pub const fn modified(mut self, modified: &'a BTreeSet<usize>) -> Self {
+        self.modified = Some(modified);
+        self
+    }
 
-            let offset_address = start_line
-                .saturating_add(line_index as usize)
-                .saturating_mul(per_row as usize);
+    /// Style used to render the [`modified`](Self::modified) addresses.
+    ///
+    /// Defaults to bold light red.
+    pub const fn modified_style(mut self, style: Style) -> Self {
+        self.modified_style = style;
+        self
+    }
 
-            let address_text = format!("{offset_address:>address_width$x}: ");
-            buffer.set_stringn(x, y, address_text, area.width as usize, ADDRESS_STYLE);
+    /// Compare the data with the `other` data and render differing bytes with the [`diff_style`](Self::diff_style).
+    ///
+    /// Bytes beyond the length of `other` count as different.
+    pub const fn diff_against(mut self, other: &'a [u8]) -> Self {
+        self.diff_against = Some(other);
+        self
+    }
 
-            for i in 0..per_row {
-                let address = offset_address.saturating_add(i as usize);
-                let Some(value) = self.data.get(address) else {
-                    break;
-                };
-                let character = *value as char;
-                let style = if Some(address) == state.selected_address {
-                    self.highlight_style
-                } else {
-                    color(character)
-                };
+    /// Style used to render bytes differing from the [`diff_against`](Self::diff_against) data.
+    ///
+    /// Defaults to reversed colors.
+    pub const fn diff_style(mut self, style: Style) -> Self {
+        self.diff_style = style;
+        self
+    }
 
-                // Hex
-                {
-                    let x = positions.x_hex(i);
-                    let text = format!("{value:>2x}");
-                    buffer.set_string(x, y, text, style);
-                }
+    /// Render the bytes of the given regions with their style.
+    ///
+    /// When regions overlap the last one in the list wins.
+    /// Selection, modified, bookmark and diff styles take precedence.
+    pub const fn regions(mut self, regions: &'a [Region]) -> Self {
+        self.regions = regions;
+        self
+    }
 
-                // Char
-                {
-                    let x = positions.x_char(i);
-                    let cell = buffer.get_mut(x, y);
-                    cell.set_style(style);
-                    if character == ' ' {
-                        cell.set_symbol(" ");
-                    } else if character.is_ascii_graphic() {
-                        let array = [*value];
-                        let str = unsafe { core::str::from_utf8_unchecked(&array) };
-                        cell.set_symbol(str);
-                    } else {
-                        cell.set_symbol("·");
-                    }
-                }
-            }
-        }
+    /// Highlight every visible occurrence of the needle, for example while typing a search.
+    ///
+    /// Defaults to an empty needle which highlights nothing.
+    pub const fn search_highlight(mut self, needle: &'a [u8]) -> Self {
+        self.search_needle = needle;
+        self
     }
-}
 
-impl Widget for BinaryDataWidget<'_> {
-    fn render(self, area: Rect, buffer: &mut Buffer) {
-        let mut state = BinaryDataWidgetState::new();
-        StatefulWidget::render(self, area, buffer, &mut state);
+    /// Style of the occurrences, see [`search_highlight`](Self::search_highlight).
+    ///
+    /// Defaults to black on yellow.
+    pub const fn search_style(mut self, style: Style) -> Self {
+        self.search_style = style;
+        self
     }
-}
 
-#[cfg(test)]
-mod render_tests {
-    use super::*;
+    /// Style the char column of runs of at least `min_string_len` printable ASCII bytes like the `strings` tool with the [`string_style`](Self::string_style).
+    ///
+    /// Defaults to 0 which disables the detection.
+    pub const fn min_string_len(mut self, min_string_len: usize) -> Self {
+        self.min_string_len = min_string_len;
+        self
+    }
 
-    fn render(
-        width: u16,
-        height: u16,
-        data: &[u8],
-        mut state: BinaryDataWidgetState,
-        expected: &Buffer,
-    ) {
-        let area = Rect::new(0, 0, width, height);
-        let mut buffer = Buffer::empty(area);
+    /// Style patched onto the char column of detected strings, see [`min_string_len`](Self::min_string_len).
+    ///
+    /// Defaults to underlined.
+    pub const fn string_style(mut self, style: Style) -> Self {
+        self.string_style = style;
+        self
+    }
 
-        let widget = BinaryDataWidget::new(data);
-        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+    /// Message centered in the area when there are no bytes to show.
+    ///
+    /// This is the case for empty data or an area too small for any bytes.
+    /// Defaults to no message.
+    pub const fn empty_message(mut self, message: &'a str) -> Self {
+        self.empty_message = Some(message);
+        self
+    }
 
-        // Compare without styles
-        buffer.set_style(area, Style::reset());
-        assert_eq!(&buffer, expected);
+    /// Symbol shown in the char column for the selected address instead of its byte.
+    ///
+    /// This makes the cursor unmistakable on terminals without colors.
+    /// Only the selected address itself is affected, not the rest of a selected range.
+    /// Defaults to `None` which keeps the symbol of the byte.
+    pub const fn selected_char_override(mut self, symbol: Option<char>) -> Self {
+        self.selected_char_override = symbol;
+        self
     }
 
-    #[test]
-    fn numbers() {
-        let data: Vec<u8> = (0..=0x12).collect();
-        let state = BinaryDataWidgetState::new();
-        let expected = Buffer::with_lines([
-            " 0:  0 1  2 3 ···· ",
-            " 4:  4 5  6 7 ···· ",
-            " 8:  8 9  a b ···· ",
-            " c:  c d  e f ···· ",
-            "10: 1011 12   ···  ",
-            "                   ",
-        ]);
-        render(19, 6, &data, state, &expected);
+    /// Symbol shown in the char column for bytes which are not printable.
+    ///
+    /// Defaults to `·`.
+    pub const fn non_printable_symbol(mut self, symbol: char) -> Self {
+        self.non_printable_symbol = symbol;
+        self
     }
 
-    #[test]
-    fn characters() {
-        let data: Vec<u8> = ('A'..='Z').map(|char| char as u8).collect();
-        let state = BinaryDataWidgetState::new();
-        let expected = Buffer::with_lines([
-            " 0: 4142 4344 ABCD ",
-            " 4: 4546 4748 EFGH ",
-            " 8: 494a 4b4c IJKL ",
-            " c: 4d4e 4f50 MNOP ",
-            "10: 5152 5354 QRST ",
-            "14: 5556 5758 UVWX ",
-            "18: 595a      YZ   ",
-            "                   ",
-        ]);
-        render(19, 8, &data, state, &expected);
+    /// Show the control bytes `0x00..0x20` as Unicode control pictures like `␀`, `␉` or `␊` in the char column.
+    ///
+    /// Other bytes which are not printable still use the [`non_printable_symbol`](Self::non_printable_symbol).
+    /// Defaults to `false`.
+    pub const fn control_pictures(mut self, control_pictures: bool) -> Self {
+        self.control_pictures = control_pictures;
+        self
+    }
+
+    /// Fill the cells after the end of the data on the last row with the symbol so it aligns with the full rows.
+    ///
+    /// Defaults to `None` which keeps them blank.
+    pub const fn pad_last_row_symbol(mut self, symbol: Option<char>) -> Self {
+        self.pad_last_row_symbol = symbol;
+        self
+    }
+
+    /// Overwrite the cells of the char column after the end of the data on the last row with spaces.
+    ///
+    /// This keeps the char column `per_row` wide regardless of the data length, even when the buffer was not cleared before.
+    /// The [`pad_last_row_symbol`](Self::pad_last_row_symbol) takes precedence when set.
+    /// Defaults to `false`.
+    pub const fn pad_char_column(mut self, pad_char_column: bool) -> Self {
+        self.pad_char_column = pad_char_column;
+        self
+    }
+
+    /// Offset added to the displayed addresses.
+    ///
+    /// Useful to show the load address of a memory dump.
+    /// This only changes the address column, the addresses of the [`BinaryDataWidgetState`] stay relative to the data.
+    pub const fn base_address(mut self, base_address: u64) -> Self {
+        self.layout.base_address = base_address;
+        self
+    }
+
+    /// Only show the bytes within the `range` of the data.
+    ///
+    /// The address column shows the addresses within the whole data, combined with the [`base_address`](Self::base_address).
+    /// The addresses of the [`BinaryDataWidgetState`] are relative to the start of the `range` and are limited to it.
+    /// This includes its bookmarks and search results, so pass the bytes within the `range` to methods like [`BinaryDataWidgetState::find`].
+    /// This also applies to the addresses of the [`regions`](Self::regions), the [`modified`](Self::modified) ones and the [`diff_against`](Self::diff_against) data.
+    /// The [`byte_style_fn`](Self::byte_style_fn) and the [`row_marker_fn`](Self::row_marker_fn) receive addresses within the whole data like the address column.
+    /// A `range` beyond the end of the data is limited to the data.
+    pub const fn data_range(mut self, range: Range<usize>) -> Self {
+        self.data_range = Some(range);
+        self
+    }
+
+    /// Format of the byte values in the hex column.
+    ///
+    /// Defaults to [`ByteFormat::Hex`].
+    pub const fn byte_format(mut self, byte_format: ByteFormat) -> Self {
+        self.layout.byte_format = byte_format;
+        self
+    }
+
+    /// Symbols shown in the char column for each byte value.
+    ///
+    /// This allows for showing code pages like CP437 or Latin-1.
+    /// The styling of the bytes is not changed by this.
+    pub const fn char_map(mut self, char_map: &'a [char; 256]) -> Self {
+        self.char_map = Some(char_map);
+        self
+    }
+
+    /// Show each group of `word_size` bytes as a single hex number like `deadbeef` instead of separate bytes.
+    ///
+    /// With [`ByteOrder::ReversedGroups`] the words are read as little-endian.
+    /// This replaces the [`group_size`](Self::group_size) and always uses hex digits.
+    /// Clicking a word selects its first byte.
+    /// Words cut off at the end of the data or the row are shown as separate bytes.
+    /// Sizes of `0` disable the word view. Defaults to `None`.
+    pub const fn word_size(mut self, word_size: Option<u8>) -> Self {
+        self.layout.word_size = match word_size {
+            Some(0) | None => None,
+            Some(size) => Some(size),
+        };
+        self
+    }
+
+    /// Amount of bytes grouped together in the hex column.
+    ///
+    /// Defaults to 2 bytes per group. A group size of `0` is treated as `1`.
+    pub const fn group_size(mut self, group_size: u16) -> Self {
+        self.layout.hex_layout.group_size = if group_size == 0 { 1 } else { group_size };
+        self
+    }
+
+    /// Spacing of the columns and the grouping of the hex column.
+    ///
+    /// This replaces the [`group_size`](Self::group_size).
+    /// Defaults to [`HexLayout::new`].
+    pub const fn layout(mut self, layout: HexLayout) -> Self {
+        let group_size = if layout.group_size == 0 {
+            1
+        } else {
+            layout.group_size
+        };
+        self.layout.hex_layout = HexLayout {
+            group_size,
+            ..layout
+        };
+        self
+    }
+
+    /// Amount of blank rows inserted between the rows of data.
+    ///
+    /// Defaults to `0`.
+    pub const fn row_spacing(mut self, row_spacing: u16) -> Self {
+        self.layout.row_spacing = row_spacing;
+        self
+    }
+
+    /// Order of the bytes within each group in the hex column, see [`group_size`](Self::group_size).
+    ///
+    /// Only the hex column is reordered. The char column and the addresses stay in natural order.
+    /// Defaults to [`ByteOrder::Native`].
+    pub const fn byte_order(mut self, byte_order: ByteOrder) -> Self {
+        self.layout.byte_order = byte_order;
+        self
+    }
+
+    /// Draw a vertical rule `│` every this many bytes in the hex column to help counting columns.
+    ///
+    /// It is drawn into the whitespace between the groups so bytes are not shifted.
+    /// Boundaries without whitespace in front, see [`group_size`](Self::group_size), get no rule.
+    /// Defaults to no rule.
+    pub const fn column_rule_every(mut self, bytes: u16) -> Self {
+        self.column_rule_every = if bytes == 0 { None } else { Some(bytes) };
+        self
+    }
+
+    /// Style of the rule, see [`column_rule_every`](Self::column_rule_every).
+    ///
+    /// Defaults to dark gray.
+    pub const fn column_rule_style(mut self, style: Style) -> Self {
+        self.column_rule_style = style;
+        self
+    }
+
+    /// Symbol rendered between the groups of the hex column.
+    ///
+    /// Defaults to a space.
+    pub const fn group_separator(mut self, separator: char) -> Self {
+        self.group_separator = separator;
+        self
+    }
+
+    /// Amount of columns between the hex and the char column.
+    ///
+    /// Same as the [`char_gap`](HexLayout::char_gap) of the [`layout`](Self::layout).
+    /// Defaults to 1.
+    pub const fn hex_char_gap(mut self, gap: u16) -> Self {
+        self.layout.hex_layout.char_gap = gap;
+        self
+    }
+
+    /// Symbol rendered in the middle of the gap between the hex and the char column on every row.
+    ///
+    /// Nothing is rendered without a [`hex_char_gap`](Self::hex_char_gap) or when one of the columns is hidden.
+    /// Defaults to none which keeps the gap blank.
+    pub const fn hex_char_separator(mut self, separator: char) -> Self {
+        self.hex_char_separator = Some(separator);
+        self
+    }
+
+    /// Styles used for the different kinds of bytes.
+    ///
+    /// Defaults to the same styles as [`color()`].
+    pub const fn color_scheme(mut self, color_scheme: ColorScheme) -> Self {
+        self.color_scheme = color_scheme;
+        self
+    }
+
+    /// Style of the `0x00` bytes instead of the one of the [`color_scheme`](Self::color_scheme).
+    ///
+    /// Defaults to `None` which uses the [`ColorScheme`].
+    pub const fn null_byte_style(mut self, style: Option<Style>) -> Self {
+        self.null_byte_style = style;
+        self
+    }
+
+    /// Style of the `0xff` bytes instead of the one of the [`color_scheme`](Self::color_scheme).
+    ///
+    /// Defaults to `None` which uses the [`ColorScheme`].
+    pub const fn fill_byte_style(mut self, style: Option<Style>) -> Self {
+        self.fill_byte_style = style;
+        self
+    }
+
+    /// Style each byte with the given function instead of the [`ColorScheme`].
+    ///
+    /// The function receives the address and the value of the byte.
+    /// The selected byte is still styled with the [`highlight_style`](Self::highlight_style).
+    pub fn byte_style_fn<F>(mut self, style_fn: F) -> Self
+    where
+        F: Fn(usize, u8) -> Style + 'a,
+    {
+        self.byte_style_fn = Some(Callback(Rc::new(style_fn)));
+        self
+    }
+
+    /// Show a marker like a breakpoint in a one column gutter between the addresses and the data.
+    ///
+    /// The function receives the first address of each row and returns the symbol and its style.
+    /// Rows without a marker return `None`.
+    pub fn row_marker_fn<F>(mut self, marker_fn: F) -> Self
+    where
+        F: Fn(usize) -> Option<(char, Style)> + 'a,
+    {
+        self.row_marker_fn = Some(Callback(Rc::new(marker_fn)));
+        self.layout.show_row_marker = true;
+        self
+    }
+
+    /// Reserve the top row for a header showing the index of each column.
+    ///
+    /// This reduces the height available for the data by one.
+    pub const fn show_header(mut self, show_header: bool) -> Self {
+        self.show_header = show_header;
+        self
+    }
+
+    /// Render a short hint when the area is too small for any bytes.
+    ///
+    /// Otherwise the area stays empty which might be confusing.
+    /// Defaults to `false`.
+    pub const fn show_too_small_hint(mut self, show_too_small_hint: bool) -> Self {
+        self.show_too_small_hint = show_too_small_hint;
+        self
+    }
+
+    /// Reserve the bottom row for a footer showing the CRC32 of the whole data like `CRC32: cbf43926`.
+    ///
+    /// This reduces the height available for the data by one.
+    /// The checksum is cached in the [`BinaryDataWidgetState`] until the length of the data or the location of its [`DataSource::as_slice`] changes.
+    /// Call [`BinaryDataWidgetState::invalidate_checksum`] after changing the bytes otherwise.
+    /// Defaults to `false`.
+    pub const fn show_checksum(mut self, show_checksum: bool) -> Self {
+        self.show_checksum = show_checksum;
+        self
+    }
+
+    /// Reserve the bottom row for the value of the selected byte like `0x41 65 'A' 0b01000001`.
+    ///
+    /// This reduces the height available for the data by one.
+    /// The row stays empty without a selection.
+    /// With the [`show_checksum`](Self::show_checksum) footer this row is below the checksum.
+    /// Defaults to `false`.
+    pub const fn show_detail_line(mut self, show_detail_line: bool) -> Self {
+        self.show_detail_line = show_detail_line;
+        self
+    }
+
+    /// Reserve two columns on the right for a minimap of the whole data.
+    ///
+    /// Each row of the minimap summarizes an equal part of the data by the density of its printable bytes, from blank over `░▒▓` to `█`.
+    /// The last column stays free for the scrollbar when there is no block.
+    /// At most 256 bytes of each part are sampled to keep the render of large data fast.
+    /// Defaults to `false`.
+    pub const fn show_minimap(mut self, show_minimap: bool) -> Self {
+        self.show_minimap = show_minimap;
+        self
+    }
+
+    /// Split off the header row from the inner area when enabled.
+    ///
+    /// Returns the header area and the remaining area for the data.
+    const fn split_header(&self, inner: Rect) -> (Option<Rect>, Rect) {
+        if !self.show_header {
+            return (None, inner);
+        }
+        let header = Rect {
+            height: if inner.height > 0 { 1 } else { 0 },
+            ..inner
+        };
+        let data = Rect {
+            y: inner.y.saturating_add(header.height),
+            height: inner.height.saturating_sub(header.height),
+            ..inner
+        };
+        (Some(header), data)
+    }
+
+    /// Split off the footer row from the inner area when enabled.
+    ///
+    /// Returns the footer area and the remaining area for the data.
+    const fn split_footer(&self, inner: Rect) -> (Option<Rect>, Rect) {
+        Self::split_bottom_row(self.show_checksum, inner)
+    }
+
+    /// Split off the detail line from the inner area when enabled.
+    ///
+    /// Returns the detail line area and the remaining area for the data.
+    const fn split_detail_line(&self, inner: Rect) -> (Option<Rect>, Rect) {
+        Self::split_bottom_row(self.show_detail_line, inner)
+    }
+
+    /// Split off the minimap columns from the inner area when enabled.
+    ///
+    /// Returns the minimap area and the remaining area for the data.
+    const fn split_minimap(&self, inner: Rect) -> (Option<Rect>, Rect) {
+        const WIDTH: u16 = 2;
+        if !self.show_minimap || inner.width < WIDTH {
+            return (None, inner);
+        }
+        let data = Rect {
+            width: inner.width - WIDTH,
+            ..inner
+        };
+        let minimap = Rect {
+            x: data.right(),
+            width: WIDTH,
+            ..inner
+        };
+        (Some(minimap), data)
+    }
+
+    const fn split_bottom_row(enabled: bool, inner: Rect) -> (Option<Rect>, Rect) {
+        if !enabled || inner.height == 0 {
+            return (None, inner);
+        }
+        let data = Rect {
+            height: inner.height - 1,
+            ..inner
+        };
+        let footer = Rect {
+            y: data.bottom(),
+            height: 1,
+            ..inner
+        };
+        (Some(footer), data)
+    }
+
+    /// Area of the data within the inner area of the block.
+    ///
+    /// Splits off the same rows and columns as the render.
+    const fn data_area(&self, inner: Rect) -> Rect {
+        let (_, inner) = self.split_header(inner);
+        let (_, inner) = self.split_detail_line(inner);
+        let (_, inner) = self.split_footer(inner);
+        let (_, inner) = self.split_minimap(inner);
+        inner
+    }
+}
+
+impl<D: DataSource> BinaryDataWidget<'_, D> {
+    /// The part of the data which is shown.
+    fn source(&self) -> DataWindow<'_, D> {
+        DataWindow::new(&self.data, self.data_range.clone())
+    }
+
+    /// Layout options with the displayed addresses shifted to the start of the shown data.
+    fn layout_options(&self) -> LayoutOptions {
+        let mut options = self.layout;
+        options.base_address = options
+            .base_address
+            .saturating_add(self.source().start() as u64);
+        options
+    }
+
+    /// Amount of bytes shown, which is the length of the [`data_range`](Self::data_range) when set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.source().len()
+    }
+
+    /// Returns `true` when there are no bytes to show.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.source().is_empty()
+    }
+
+    /// Returns the biggest address of the [`BinaryDataWidgetState`] which can be selected.
+    ///
+    /// Returns `None` when there are no bytes to show.
+    #[must_use]
+    pub fn last_address(&self) -> Option<usize> {
+        self.len().checked_sub(1)
+    }
+
+    /// Returns the plain text the widget would show in the given area without any styles.
+    ///
+    /// Each row is separated by a newline and trailing whitespace is removed.
+    /// The block and scrollbar are not part of the text.
+    /// Useful for logging or snapshot tests.
+    #[must_use]
+    pub fn render_to_string(&self, area: Rect, state: &BinaryDataWidgetState) -> String {
+        let inner = self.block.inner_if_some(area);
+        let mut buffer = Buffer::empty(inner);
+        let mut state = state.clone();
+        self.render_content(inner, inner, &mut buffer, &mut state, false);
+
+        let width = usize::from(inner.width.max(1));
+        let mut text = buffer
+            .content
+            .chunks(width)
+            .map(|row| {
+                let line = row.iter().map(Cell::symbol).collect::<String>();
+                line.trim_end().to_owned()
+            })
+            .collect::<Vec<_>>();
+        while text.last().is_some_and(String::is_empty) {
+            text.pop();
+        }
+        text.join("\n")
+    }
+
+    /// Returns the range of addresses which will be shown from the [`DataSource`] when rendered into the given area.
+    ///
+    /// Useful to prefetch the bytes of lazy data sources before rendering.
+    /// Some options read bytes outside of this range:
+    /// - [`show_minimap`](Self::show_minimap) samples bytes of the whole data
+    /// - [`min_string_len`](Self::min_string_len) reads up to `min_string_len - 1` bytes before and after the range
+    /// - [`search_highlight`](Self::search_highlight) reads up to the needle length minus one bytes before and after the range
+    /// - [`show_detail_line`](Self::show_detail_line) reads the selected byte
+    /// - [`show_checksum`](Self::show_checksum) reads every byte when the cached checksum is outdated
+    #[must_use]
+    pub fn visible_byte_range(&self, area: Rect, state: &BinaryDataWidgetState) -> Range<usize> {
+        let inner = self.data_area(self.block.inner_if_some(area));
+        let Some(positions) =
+            RenderPositions::new(inner, self.source().len(), self.layout_options())
+        else {
+            return 0..0;
+        };
+        let (_, inner) = self.split_horizontal_scrollbar(positions, inner, true);
+        let available_height = usize::from(positions.lines_in_height(inner.height));
+        let start_line = state.start_line(positions, available_height);
+        let end_line = start_line.saturating_add(available_height);
+        let per_row = usize::from(positions.per_row);
+        let start = start_line.saturating_mul(per_row);
+        let source = self.source();
+        let end = end_line.saturating_mul(per_row).min(source.len());
+        start.saturating_add(source.start())..end.saturating_add(source.start())
+    }
+
+    /// Returns the amount of lines that could be written with the given area width.
+    ///
+    /// With this information the height of the resulting widget can be limited.
+    /// The [`row_spacing`](Self::row_spacing) between the lines is included.
+    #[must_use]
+    pub fn get_max_lines_of_data_in_area(&self, area: Rect) -> usize {
+        let inner = self.data_area(self.block.inner_if_some(area));
+        RenderPositions::new(inner, self.source().len(), self.layout_options())
+            .map_or(0, |positions| positions.height_of_all_lines())
+    }
+
+    /// Returns whether the data can be shown in the given area.
+    ///
+    /// When this is `false` the area is too small for a single row of data or there is no data at all.
+    /// This allows to fall back to something else before rendering.
+    #[must_use]
+    pub fn can_render(&self, area: Rect) -> bool {
+        self.layout_preview(area).is_some()
+    }
+
+    /// Returns the positions of the columns when rendered into the given area without rendering.
+    ///
+    /// Returns `None` when nothing would be shown, see [`can_render`](Self::can_render).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::layout::Rect;
+    /// use ratatui_binary_data_widget::BinaryDataWidget;
+    ///
+    /// let data = [0; 0x100];
+    /// let widget = BinaryDataWidget::new(&data);
+    /// let layout = widget.layout_preview(Rect::new(0, 0, 40, 10)).unwrap();
+    /// assert_eq!(layout.per_row(), 8);
+    /// let hex_columns = (0..layout.per_row()).map(|i| layout.x_hex(i)).collect::<Vec<_>>();
+    /// assert_eq!(hex_columns, [4, 6, 9, 11, 14, 16, 19, 21]);
+    /// assert_eq!(layout.x_char(0), 24);
+    /// ```
+    #[must_use]
+    pub fn layout_preview(&self, area: Rect) -> Option<LayoutPreview> {
+        let inner = self.data_area(self.block.inner_if_some(area));
+        RenderPositions::new(inner, self.source().len(), self.layout_options())
+            .map(LayoutPreview::new)
+    }
+
+    /// Returns the style of the byte at the given address.
+    ///
+    /// Precedence: cursor, selection, hover, modified, bookmark, diff, region, [`byte_style_fn`](Self::byte_style_fn), [`null_byte_style`](Self::null_byte_style) and [`fill_byte_style`](Self::fill_byte_style), [`ColorScheme`].
+    /// The [`crosshair_style`](Self::crosshair_style) is patched on top of everything but the selection.
+    fn byte_style(
+        &self,
+        state: &BinaryDataWidgetState,
+        selected_range: Option<&RangeInclusive<usize>>,
+        per_row: usize,
+        address: usize,
+        value: u8,
+    ) -> Style {
+        if let (Some(cursor_style), Some(selected)) = (self.cursor_style, state.selected_address) {
+            if selected == address {
+                return cursor_style;
+            }
+        }
+        if Self::is_selected(state, selected_range, address) {
+            return self.highlight_style;
+        }
+        if let (Some(hover_style), Some(hovered)) = (self.hover_style, state.hovered_address) {
+            if hovered == address {
+                return hover_style;
+            }
+        }
+        let style = self.unselected_byte_style(state, address, value);
+        match (self.crosshair_style, state.selected_address) {
+            (Some(crosshair_style), Some(selected))
+                if selected / per_row == address / per_row
+                    || selected % per_row == address % per_row =>
+            {
+                style.patch(crosshair_style)
+            }
+            _ => style,
+        }
+    }
+
+    fn is_selected(
+        state: &BinaryDataWidgetState,
+        selected_range: Option<&RangeInclusive<usize>>,
+        address: usize,
+    ) -> bool {
+        Some(address) == state.selected_address
+            || selected_range.is_some_and(|range| range.contains(&address))
+            || state.selection_set.contains(&address)
+    }
+
+    /// Returns for each address of the `window` whether it is part of a run of at least `min_string_len` printable bytes.
+    ///
+    /// Bytes around the window are considered too so runs crossing its edges are detected.
+    fn string_mask(&self, window: Range<usize>) -> Vec<bool> {
+        let mut mask = vec![false; window.len()];
+        let Some(lookaround) = self.min_string_len.checked_sub(1) else {
+            return mask;
+        };
+        let is_printable = |address: usize| {
+            self.source()
+                .byte(address)
+                .is_some_and(|value| value.is_ascii_graphic() || value == b' ')
+        };
+        let scan_end = window
+            .end
+            .saturating_add(lookaround)
+            .min(self.source().len());
+        let scan_start = window.start.saturating_sub(lookaround);
+        let mut run_start = scan_start;
+        for address in scan_start..=scan_end {
+            if address < scan_end && is_printable(address) {
+                continue;
+            }
+            if address - run_start >= self.min_string_len {
+                for in_string in run_start.max(window.start)..address.min(window.end) {
+                    mask[in_string - window.start] = true;
+                }
+            }
+            run_start = address.saturating_add(1);
+        }
+        mask
+    }
+
+    /// Returns for each address of the `window` whether it is part of an occurrence of the `search_needle`.
+    ///
+    /// Occurrences crossing the edges of the window are detected too.
+    fn search_mask(&self, window: Range<usize>) -> Vec<bool> {
+        let mut mask = vec![false; window.len()];
+        let needle = self.search_needle;
+        let Some(lookaround) = needle.len().checked_sub(1) else {
+            return mask;
+        };
+        let last_start = self.source().len().saturating_sub(needle.len());
+        for start in window.start.saturating_sub(lookaround)..window.end.min(last_start + 1) {
+            let matches = needle
+                .iter()
+                .enumerate()
+                .all(|(offset, expected)| self.source().byte(start + offset) == Some(*expected));
+            if matches {
+                let end = start + needle.len();
+                for in_match in start.max(window.start)..end.min(window.end) {
+                    mask[in_match - window.start] = true;
+                }
+            }
+        }
+        mask
+    }
+
+    fn unselected_byte_style(
+        &self,
+        state: &BinaryDataWidgetState,
+        address: usize,
+        value: u8,
+    ) -> Style {
+        if self
+            .modified
+            .is_some_and(|modified| modified.contains(&address))
+        {
+            return self.modified_style;
+        }
+        if let Some(bookmark_style) = self.bookmark_style {
+            if state.bookmarks.binary_search(&address).is_ok() {
+                return bookmark_style;
+            }
+        }
+        if let Some(other) = self.diff_against {
+            if other.get(address) != Some(&value) {
+                return self.diff_style;
+            }
+        }
+        if let Some(region) = self
+            .regions
+            .iter()
+            .rev()
+            .find(|region| region.range.contains(&address))
+        {
+            return region.style;
+        }
+        if let Some(byte_style_fn) = &self.byte_style_fn {
+            return byte_style_fn(self.source().start().saturating_add(address), value);
+        }
+        let special_style = match value {
+            0x00 => self.null_byte_style,
+            0xff => self.fill_byte_style,
+            _ => None,
+        };
+        special_style.unwrap_or_else(|| self.color_scheme.style(value as char))
+    }
+}
+
+const DIGITS_LOWERCASE: &[u8; 16] = b"0123456789abcdef";
+const DIGITS_UPPERCASE: &[u8; 16] = b"0123456789ABCDEF";
+
+impl<D: DataSource> StatefulWidget for BinaryDataWidget<'_, D> {
+    type State = BinaryDataWidgetState;
+
+    fn render(mut self, full_area: Rect, buffer: &mut Buffer, state: &mut Self::State) {
+        let visible_area = full_area.intersection(buffer.area);
+        if visible_area != full_area {
+            // Render into a scratch buffer covering the whole area and copy only the visible part.
+            // This keeps the layout of the full area while never writing outside of the buffer.
+            let mut scratch = Buffer::empty(full_area);
+            Self::copy_cells(buffer, &mut scratch, visible_area);
+            StatefulWidget::render(self, full_area, &mut scratch, state);
+            Self::copy_cells(&scratch, buffer, visible_area);
+            return;
+        }
+
+        buffer.set_style(full_area, self.style);
+
+        // Get the inner area inside a possible block, otherwise use the full area
+        let area = self.block.take().map_or(full_area, |block| {
+            let inner_area = block.inner(full_area);
+            block.render(full_area, buffer);
+            inner_area
+        });
+        self.render_content(full_area, area, buffer, state, true);
+    }
+}
+
+impl<D: DataSource> BinaryDataWidget<'_, D> {
+    /// Copy the cells within the area which has to be within both buffers.
+    fn copy_cells(from: &Buffer, to: &mut Buffer, area: Rect) {
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                to.get_mut(x, y).clone_from(from.get(x, y));
+            }
+        }
+    }
+
+    /// Split off the bottom row of the data area for the horizontal scrollbar when the rows do not fit.
+    ///
+    /// Returns the scrollbar area and the remaining area for the data.
+    const fn split_horizontal_scrollbar(
+        &self,
+        positions: RenderPositions,
+        data_area: Rect,
+        with_scrollbar: bool,
+    ) -> (Option<Rect>, Rect) {
+        let enabled = with_scrollbar
+            && self.horizontal_scrollbar.is_some()
+            && positions.max_offset_column() > 0;
+        if !enabled || data_area.height <= 1 {
+            return (None, data_area);
+        }
+        Self::split_bottom_row(true, data_area)
+    }
+
+    /// Render everything within the inner area of the block.
+    ///
+    /// The scrollbar is rendered on the right of the `full_area`.
+    #[allow(clippy::too_many_lines)]
+    fn render_content(
+        &self,
+        full_area: Rect,
+        area: Rect,
+        buffer: &mut Buffer,
+        state: &mut BinaryDataWidgetState,
+        with_scrollbar: bool,
+    ) {
+        let (header_area, data_area) = self.split_header(area);
+        let (detail_line_area, data_area) = self.split_detail_line(data_area);
+        let (footer_area, data_area) = self.split_footer(data_area);
+        let (minimap_area, data_area) = self.split_minimap(data_area);
+
+        state.last_visible_lines = 0;
+        let Some(positions) =
+            state.update_render_positions(data_area, self.source().len(), self.layout_options())
+        else {
+            const TOO_SMALL_HINT: &str = "too narrow";
+            if self.show_too_small_hint && !self.source().is_empty() {
+                let hint = if usize::from(area.width) >= TOO_SMALL_HINT.len() {
+                    TOO_SMALL_HINT
+                } else {
+                    "…"
+                };
+                Self::render_centered(area, buffer, hint);
+            } else if let Some(message) = self.empty_message {
+                Self::render_centered(area, buffer, message);
+            }
+            return;
+        };
+        let area = data_area;
+
+        if let Some(footer_area) = footer_area {
+            let checksum = state.checksum(&self.source());
+            let text = if self.uppercase {
+                format!("CRC32: {checksum:08X}")
+            } else {
+                format!("CRC32: {checksum:08x}")
+            };
+            buffer.set_stringn(
+                footer_area.x,
+                footer_area.y,
+                text,
+                footer_area.width as usize,
+                self.address_style,
+            );
+        }
+        if let Some(detail_line_area) = detail_line_area {
+            let value = state
+                .selected_address
+                .and_then(|selected| self.source().byte(selected.min(positions.biggest_address)));
+            if let Some(value) = value {
+                let character = if value.is_ascii_graphic() || value == b' ' {
+                    value as char
+                } else {
+                    self.non_printable_symbol
+                };
+                let text = if self.uppercase {
+                    format!("0x{value:02X} {value} '{character}' 0b{value:08b}")
+                } else {
+                    format!("0x{value:02x} {value} '{character}' 0b{value:08b}")
+                };
+                buffer.set_stringn(
+                    detail_line_area.x,
+                    detail_line_area.y,
+                    text,
+                    detail_line_area.width as usize,
+                    self.address_style,
+                );
+            }
+        }
+        if let Some(minimap_area) = minimap_area {
+            self.render_minimap(minimap_area, buffer);
+        }
+        let RenderPositions {
+            address_width,
+            per_row,
+            visible_per_row,
+            available_data_lines,
+            ..
+        } = positions;
+
+        // Ensure offset is actually in data range and at the start of a row
+        state.offset_address = state
+            .offset_address
+            .min(self.source().len().saturating_sub(1));
+        state.offset_address -= state.offset_address % per_row as usize;
+        // Ensure selected_address is actually selectable
+        if let Some(selected) = state.selected_address {
+            state.selected_address = Some(self.source().len().saturating_sub(1).min(selected));
+        }
+
+        // Scroll the column of the selection into view
+        let max_offset_column = positions.max_offset_column();
+        if let (true, Some(selected)) = (
+            state.ensure_selected_in_view_on_next_render,
+            state.selected_address,
+        ) {
+            #[allow(clippy::cast_possible_truncation)]
+            let column = (selected % per_row as usize) as u16;
+            let is_right_of_view = |offset_column: u16| {
+                column >= offset_column.saturating_add(positions.visible_columns(offset_column))
+            };
+            if column < state.offset_column {
+                state.offset_column = column;
+            } else if is_right_of_view(state.offset_column) {
+                state.offset_column = column.saturating_add(1).saturating_sub(visible_per_row);
+                while state.offset_column < column && is_right_of_view(state.offset_column) {
+                    state.offset_column += 1;
+                }
+            }
+        }
+        // Ensure the rows stay filled when scrolled horizontally
+        state.offset_column = state.offset_column.min(max_offset_column);
+        let offset_column = state.offset_column;
+        let visible_columns = positions.visible_columns(offset_column);
+
+        if let Some(header_area) = header_area {
+            for i in 0..visible_columns {
+                let column = i.saturating_add(offset_column);
+                if self.layout.show_hex_column {
+                    // Label the byte shown at this position
+                    let column = positions
+                        .hex_slot(offset_column, visible_columns, i)
+                        .saturating_add(offset_column);
+                    let width = positions.byte_width as usize;
+                    let text = if self.uppercase {
+                        format!("{column:>width$X}")
+                    } else {
+                        format!("{column:>width$x}")
+                    };
+                    buffer.set_stringn(
+                        positions.x_hex(offset_column, i),
+                        header_area.y,
+                        text,
+                        width,
+                        self.address_style,
+                    );
+                } else {
+                    // Only the last digit fits above a char
+                    let text = if self.uppercase {
+                        format!("{:X}", column % 16)
+                    } else {
+                        format!("{:x}", column % 16)
+                    };
+                    buffer.set_stringn(
+                        positions.x_char(i),
+                        header_area.y,
+                        text,
+                        1,
+                        self.address_style,
+                    );
+                }
+            }
+        }
+
+        let (horizontal_scrollbar_area, area) =
+            self.split_horizontal_scrollbar(positions, area, with_scrollbar);
+
+        let available_height = usize::from(positions.lines_in_height(area.height));
+        state.last_available_height = available_height;
+
+        let mut start_line = state.start_line(positions, available_height);
+        if state.ensure_selected_in_view_on_next_render {
+            state.offset_address = start_line.saturating_mul(per_row as usize);
+            state.ensure_selected_in_view_on_next_render = false;
+            state.center_selected_on_next_render = false;
+        }
+        let data_grew = self.source().len() > state.last_data_len;
+        state.last_data_len = self.source().len();
+        if state.follow && data_grew {
+            start_line = available_data_lines.saturating_sub(available_height);
+            state.offset_address = start_line.saturating_mul(per_row as usize);
+        }
+
+        let visible_lines = available_data_lines
+            .saturating_sub(start_line)
+            .min(available_height);
+        state.last_visible_lines = visible_lines;
+
+        if let (true, Some(scrollbar)) = (with_scrollbar, &self.scrollbar) {
+            // Render Scrollbar
+            // When there is a border to the right it is rendered on top.
+            // -> Scrollbar and data always visible
+            // When there is no border it is still rendered before the binary data
+            // -> the scrollbar might not be visible but the data always is
+            let overscroll_workaround = available_data_lines.saturating_sub(available_height);
+            let mut scrollbar_state = ScrollbarState::new(overscroll_workaround)
+                .position(start_line)
+                // Should be available_height but with the current overscroll workaround this looks nicer
+                .viewport_content_length(visible_lines);
+            let scrollbar_area = Rect {
+                // Inner height to be exactly as the content
+                y: area.y,
+                height: area.height,
+                // Outer width to stay on the right border
+                x: full_area.x,
+                width: full_area.width,
+            };
+            scrollbar
+                .clone()
+                .render(scrollbar_area, buffer, &mut scrollbar_state);
+        }
+
+        if let (Some(scrollbar), Some(scrollbar_area)) =
+            (&self.horizontal_scrollbar, horizontal_scrollbar_area)
+        {
+            let mut scrollbar_state = ScrollbarState::new(max_offset_column as usize)
+                .position(offset_column as usize)
+                .viewport_content_length(visible_columns as usize);
+            scrollbar
+                .clone()
+                .render(scrollbar_area, buffer, &mut scrollbar_state);
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        let visible_lines = visible_lines as u16;
+        let x = area.left();
+        let selected_range = state.selected_range();
+        let window_start = start_line.saturating_mul(per_row as usize);
+        let window_end = window_start
+            .saturating_add((visible_lines as usize).saturating_mul(per_row as usize))
+            .min(self.source().len());
+        let group_size = positions.group_size;
+        let string_mask = self.string_mask(window_start..window_end);
+        let search_mask = self.search_mask(window_start..window_end);
+        let base_address = self.layout_options().base_address;
+
+        for line_index in 0..visible_lines {
+            let y = area
+                .top()
+                .saturating_add(line_index.saturating_mul(positions.row_height()));
+            let line = start_line.saturating_add(line_index as usize);
+
+            if let (Some(row_stripe_style), 0) = (self.row_stripe_style, line % 2) {
+                buffer.set_style(
+                    Rect {
+                        y,
+                        height: 1,
+                        ..area
+                    },
+                    row_stripe_style,
+                );
+            }
+
+            let offset_address = line.saturating_mul(per_row as usize);
+
+            let (displayed_address, sign) =
+                if let (AddressOrigin::RelativeToSelection, Some(selected)) =
+                    (self.layout.address_origin, state.selected_address)
+                {
+                    if offset_address >= selected {
+                        ((offset_address - selected) as u64, Some('+'))
+                    } else {
+                        ((selected - offset_address) as u64, Some('-'))
+                    }
+                } else {
+                    let displayed_address = base_address.saturating_add(offset_address as u64);
+                    (displayed_address, None)
+                };
+            if positions.show_left_address {
+                self.set_address(buffer, x, y, displayed_address, sign, address_width);
+                self.set_address_suffix(buffer, x.saturating_add(address_width), y);
+            }
+            if let Some(right_x) = positions.offset_x_right_address {
+                self.set_address(buffer, right_x, y, displayed_address, sign, address_width);
+            }
+
+            if let Some((marker, style)) = self.row_marker_fn.as_ref().and_then(|row_marker_fn| {
+                row_marker_fn(self.source().start().saturating_add(offset_address))
+            }) {
+                buffer
+                    .get_mut(positions.offset_x_marker, y)
+                    .set_char(marker)
+                    .set_style(style);
+            }
+
+            let char_gap = self.layout.hex_layout.char_gap;
+            if let Some(separator) = self.hex_char_separator.filter(|_| {
+                self.layout.show_hex_column && self.layout.show_char_column && char_gap > 0
+            }) {
+                let offset_in_gap = char_gap - char_gap / 2;
+                buffer
+                    .get_mut(positions.offset_x_char.saturating_sub(offset_in_gap), y)
+                    .set_char(separator)
+                    .set_style(self.address_style);
+            }
+
+            let row_start = offset_address.saturating_add(offset_column as usize);
+            let mut word_blank_digits = None;
+            for i in 0..visible_columns {
+                let address = row_start.saturating_add(i as usize);
+                let column = i.saturating_add(offset_column);
+                if positions.word_view && (i == 0 || column % group_size == 0) {
+                    word_blank_digits = self.word_blank_digits(
+                        positions,
+                        offset_column,
+                        visible_columns,
+                        row_start,
+                        i,
+                    );
+                }
+                let Some(value) = self.source().byte(address) else {
+                    if let Some(symbol) = self.pad_last_row_symbol {
+                        let indexes = i..visible_columns;
+                        self.pad_row(buffer, positions, offset_column, y, indexes, symbol);
+                    } else if self.pad_char_column && self.layout.show_char_column {
+                        for i in i..visible_columns {
+                            buffer
+                                .get_mut(positions.x_char(i), y)
+                                .set_symbol(" ")
+                                .set_style(self.style);
+                        }
+                    }
+                    break;
+                };
+                let character = value as char;
+                let is_selected = Self::is_selected(state, selected_range.as_ref(), address);
+                let is_search_match = search_mask
+                    .get(address.saturating_sub(window_start))
+                    .copied()
+                    .unwrap_or_default();
+                let mut style = if is_search_match && !is_selected {
+                    self.search_style
+                } else {
+                    self.byte_style(
+                        state,
+                        selected_range.as_ref(),
+                        per_row as usize,
+                        address,
+                        value,
+                    )
+                };
+                if self.dim_unselected
+                    && (selected_range.is_some() || !state.selection_set.is_empty())
+                    && !is_selected
+                {
+                    style = style.add_modifier(Modifier::DIM);
+                }
+
+                // Hex
+                if self.layout.show_hex_column {
+                    if i > 0 && column % group_size == 0 {
+                        let group_x = positions.x_hex(offset_column, i);
+                        let gap = self.layout.hex_layout.group_gap;
+                        // Keep a selected range contiguous over the gap
+                        let gap_selected = [i - 1, i].into_iter().all(|index| {
+                            let index = positions.hex_slot(offset_column, visible_columns, index);
+                            let address = row_start.saturating_add(index as usize);
+                            Self::is_selected(state, selected_range.as_ref(), address)
+                        });
+                        for x in group_x.saturating_sub(gap)..group_x {
+                            let cell = buffer.get_mut(x, y);
+                            cell.set_char(self.group_separator);
+                            if gap_selected {
+                                cell.set_style(self.highlight_style);
+                            }
+                        }
+                        if gap > 0
+                            && self
+                                .column_rule_every
+                                .is_some_and(|every| column % every == 0)
+                        {
+                            buffer
+                                .get_mut(group_x.saturating_sub(1), y)
+                                .set_char('│')
+                                .set_style(self.column_rule_style);
+                        }
+                    }
+                    let slot = positions.hex_slot(offset_column, visible_columns, i);
+                    let x = positions.x_hex(offset_column, slot);
+                    let blank_digits = word_blank_digits.map(|blank_digits: u16| {
+                        let in_word = slot.saturating_add(offset_column) % group_size;
+                        let digits_before = in_word.saturating_mul(2);
+                        blank_digits.saturating_sub(digits_before).min(2)
+                    });
+                    self.set_byte(buffer, x, y, value, style, blank_digits);
+                    if state.nibble_navigation && Some(address) == state.selected_address {
+                        let nibble_width = positions.byte_width / 2;
+                        let x = match state.nibble {
+                            Nibble::High => x,
+                            Nibble::Low => x.saturating_add(nibble_width),
+                        };
+                        buffer.set_style(
+                            Rect::new(x, y, nibble_width, 1),
+                            Style::new().add_modifier(Modifier::UNDERLINED),
+                        );
+                    }
+                }
+
+                // Char
+                if self.layout.show_char_column {
+                    let x = positions.x_char(i);
+                    let cell = buffer.get_mut(x, y);
+                    cell.set_style(style);
+                    if string_mask
+                        .get(address.saturating_sub(window_start))
+                        .copied()
+                        .unwrap_or_default()
+                        && !is_selected
+                    {
+                        cell.set_style(self.string_style);
+                    }
+                    if let (Some(symbol), Some(selected)) =
+                        (self.selected_char_override, state.selected_address)
+                    {
+                        if selected == address {
+                            cell.set_char(symbol);
+                            continue;
+                        }
+                    }
+                    if let Some(char_map) = self.char_map {
+                        cell.set_char(char_map[value as usize]);
+                    } else if self.control_pictures && value < 0x20 {
+                        let picture = char::from_u32(0x2400 + u32::from(value))
+                            .unwrap_or(self.non_printable_symbol);
+                        cell.set_char(picture);
+                    } else if character == ' ' {
+                        cell.set_symbol(" ");
+                    } else if character.is_ascii_graphic() {
+                        let array = [value];
+                        let str = unsafe { core::str::from_utf8_unchecked(&array) };
+                        cell.set_symbol(str);
+                    } else {
+                        cell.set_char(self.non_printable_symbol);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Fill the hex and char cells of the indexes on the row with the symbol.
+    fn pad_row(
+        &self,
+        buffer: &mut Buffer,
+        positions: RenderPositions,
+        offset_column: u16,
+        y: u16,
+        indexes: Range<u16>,
+        symbol: char,
+    ) {
+        let visible_columns = positions.visible_columns(offset_column);
+        for i in indexes {
+            if self.layout.show_hex_column {
+                let slot = positions.hex_slot(offset_column, visible_columns, i);
+                let x = positions.x_hex(offset_column, slot);
+                for offset in 0..positions.byte_width {
+                    buffer.get_mut(x.saturating_add(offset), y).set_char(symbol);
+                }
+            }
+            if self.layout.show_char_column {
+                buffer.get_mut(positions.x_char(i), y).set_char(symbol);
+            }
+        }
+    }
+
+    /// Amount of leading zero digits of the word starting at the index on the row which are shown blank.
+    ///
+    /// Returns `None` when the word is not complete or cut off at an edge of the view and therefore shown as separate bytes.
+    fn word_blank_digits(
+        &self,
+        positions: RenderPositions,
+        offset_column: u16,
+        visible_columns: u16,
+        row_start: usize,
+        group_start: u16,
+    ) -> Option<u16> {
+        let group_end = group_start.saturating_add(positions.group_size);
+        let in_group = offset_column.saturating_add(group_start) % positions.group_size;
+        let is_cut_off = in_group > 0 || group_end > visible_columns;
+        if is_cut_off {
+            return None;
+        }
+        self.source()
+            .byte(row_start.saturating_add(group_end as usize - 1))?;
+        let max_blank_digits = positions.group_size.saturating_mul(2).saturating_sub(1);
+        let mut blank_digits = 0;
+        for slot in group_start..group_end {
+            let index = positions.hex_slot(offset_column, visible_columns, slot);
+            let value = self
+                .source()
+                .byte(row_start.saturating_add(index as usize))?;
+            if value >> 4 != 0 {
+                break;
+            }
+            blank_digits += 1;
+            if value != 0 {
+                break;
+            }
+            blank_digits += 1;
+        }
+        Some(blank_digits.min(max_blank_digits))
+    }
+
+    /// Render the density of printable bytes of each equal part of the data into the first column of the area.
+    fn render_minimap(&self, area: Rect, buffer: &mut Buffer) {
+        const MAX_SAMPLES: usize = 256;
+        const SHADES: [char; 4] = ['░', '▒', '▓', '█'];
+        let source = self.source();
+        let len = source.len();
+        let height = usize::from(area.height);
+        for band in 0..area.height {
+            let start = usize::from(band).saturating_mul(len) / height;
+            let end = (usize::from(band) + 1).saturating_mul(len) / height;
+            let end = end.max(start.saturating_add(1)).min(len);
+            if start >= end {
+                continue;
+            }
+            let step = (end - start).div_ceil(MAX_SAMPLES);
+            let mut samples = 0;
+            let mut printable = 0;
+            for address in (start..end).step_by(step) {
+                samples += 1;
+                if source
+                    .byte(address)
+                    .is_some_and(|value| value.is_ascii_graphic() || value == b' ')
+                {
+                    printable += 1;
+                }
+            }
+            if printable == 0 {
+                continue;
+            }
+            let shade = SHADES[(printable * SHADES.len()).div_ceil(samples) - 1];
+            buffer
+                .get_mut(area.x, area.y.saturating_add(band))
+                .set_char(shade)
+                .set_style(self.address_style);
+        }
+    }
+
+    /// Render the text centered in the area, cut off when it is too long.
+    fn render_centered(area: Rect, buffer: &mut Buffer, text: &str) {
+        if area.is_empty() {
+            return;
+        }
+        let line = Line::raw(text);
+        #[allow(clippy::cast_possible_truncation)]
+        let width = line.width().min(area.width as usize) as u16;
+        let x = area.x.saturating_add((area.width - width) / 2);
+        let y = area.y.saturating_add(area.height.saturating_sub(1) / 2);
+        buffer.set_line(x, y, &line, width);
+    }
+
+    const fn digits(&self) -> &'static [u8; 16] {
+        if self.uppercase {
+            DIGITS_UPPERCASE
+        } else {
+            DIGITS_LOWERCASE
+        }
+    }
+
+    /// Write the address right aligned directly into the cells.
+    ///
+    /// The optional sign is placed in front of the digits.
+    fn set_address(
+        &self,
+        buffer: &mut Buffer,
+        x: u16,
+        y: u16,
+        address: u64,
+        mut sign: Option<char>,
+        width: u16,
+    ) {
+        let digits = self.digits();
+        let radix = u64::from(self.layout.address_format.radix());
+        let mut remaining = address;
+        for index in (0..width).rev() {
+            let cell = buffer.get_mut(x.saturating_add(index), y);
+            cell.set_style(self.address_style);
+            if remaining > 0 || index + 1 == width {
+                #[allow(clippy::cast_possible_truncation)]
+                let digit = (remaining % radix) as usize;
+                cell.set_char(digits[digit] as char);
+                remaining /= radix;
+            } else if let Some(sign) = sign.take() {
+                cell.set_char(sign);
+            } else {
+                cell.set_char(' ');
+            }
+        }
+    }
+
+    /// Write the [`HexLayout::address_suffix`] directly into the cells.
+    fn set_address_suffix(&self, buffer: &mut Buffer, mut x: u16, y: u16) {
+        for char in self.layout.hex_layout.address_suffix.chars() {
+            buffer
+                .get_mut(x, y)
+                .set_char(char)
+                .set_style(self.address_style);
+            x = x.saturating_add(1);
+        }
+    }
+
+    /// Write the value in the [`ByteFormat`] directly into the cells.
+    ///
+    /// The `blank_digits` of a word view replace the leading zero digits with whitespace.
+    /// Without them only a zero high nibble is blank.
+    fn set_byte(
+        &self,
+        buffer: &mut Buffer,
+        x: u16,
+        y: u16,
+        value: u8,
+        style: Style,
+        blank_digits: Option<u16>,
+    ) {
+        match (self.layout.byte_format, blank_digits) {
+            (ByteFormat::Hex, None) | (_, Some(_)) => {
+                let digits = self.digits();
+                let high = value >> 4;
+                let blank_digits = blank_digits.unwrap_or_else(|| u16::from(high == 0));
+                let high = if blank_digits >= 1 {
+                    ' '
+                } else {
+                    digits[high as usize] as char
+                };
+                let low = if blank_digits >= 2 {
+                    ' '
+                } else {
+                    digits[(value & 0xf) as usize] as char
+                };
+                buffer.get_mut(x, y).set_char(high).set_style(style);
+                buffer
+                    .get_mut(x.saturating_add(1), y)
+                    .set_char(low)
+                    .set_style(style);
+            }
+            (ByteFormat::Binary, None) => {
+                for bit in 0..8 {
+                    let char = if value & (0x80 >> bit) == 0 { '0' } else { '1' };
+                    buffer
+                        .get_mut(x.saturating_add(bit), y)
+                        .set_char(char)
+                        .set_style(style);
+                }
+            }
+        }
+    }
+}
+
+impl<D: DataSource> Widget for BinaryDataWidget<'_, D> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        let mut state = BinaryDataWidgetState::new();
+        StatefulWidget::render(self, area, buffer, &mut state);
+    }
+}
+
+#[cfg(test)]
+mod render_tests {
+    use super::*;
+
+    fn render(
+        width: u16,
+        height: u16,
+        data: &[u8],
+        state: BinaryDataWidgetState,
+        expected: &Buffer,
+    ) {
+        render_widget(width, height, BinaryDataWidget::new(data), state, expected);
+    }
+
+    fn render_widget<D: DataSource>(
+        width: u16,
+        height: u16,
+        widget: BinaryDataWidget<D>,
+        mut state: BinaryDataWidgetState,
+        expected: &Buffer,
+    ) {
+        let area = Rect::new(0, 0, width, height);
+        let mut buffer = Buffer::empty(area);
+
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+
+        // Compare without styles
+        buffer.set_style(area, Style::reset());
+        assert_eq!(&buffer, expected);
+    }
+
+    #[test]
+    fn numbers() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let state = BinaryDataWidgetState::new();
+        let expected = Buffer::with_lines([
+            " 0:  0 1  2 3 ···· ",
+            " 4:  4 5  6 7 ···· ",
+            " 8:  8 9  a b ···· ",
+            " c:  c d  e f ···· ",
+            "10: 1011 12   ···  ",
+            "                   ",
+        ]);
+        render(19, 6, &data, state, &expected);
+    }
+
+    #[test]
+    fn characters() {
+        let data: Vec<u8> = ('A'..='Z').map(|char| char as u8).collect();
+        let state = BinaryDataWidgetState::new();
+        let expected = Buffer::with_lines([
+            " 0: 4142 4344 ABCD ",
+            " 4: 4546 4748 EFGH ",
+            " 8: 494a 4b4c IJKL ",
+            " c: 4d4e 4f50 MNOP ",
+            "10: 5152 5354 QRST ",
+            "14: 5556 5758 UVWX ",
+            "18: 595a      YZ   ",
+            "                   ",
+        ]);
+        render(19, 8, &data, state, &expected);
+    }
+
+    #[test]
+    fn fixed_bytes_per_row() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&data).bytes_per_row(6);
+        let expected = Buffer::with_lines([
+            " 0:  0 1  2 3  4 5 ······ ",
+            " 6:  6 7  8 9  a b ······ ",
+            " c:  c d  e f 1011 ······ ",
+            "12: 12             ·      ",
+            "                          ",
+        ]);
+        render_widget(26, 5, widget, state, &expected);
+    }
+
+    #[test]
+    fn fixed_bytes_per_row_truncated() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&data).bytes_per_row(16);
+        let expected = Buffer::with_lines([
+            " 0:  0 1  2 3 ···· ",
+            "10: 1011 12   ···  ",
+            "█████              ",
+        ]);
+        render_widget(19, 3, widget, state, &expected);
+    }
+
+    #[test]
+    fn scrolled_horizontally() {
+        let data: Vec<u8> = (0x40..0x60).collect();
+        let mut state = BinaryDataWidgetState::new();
+        state.scroll_right(4);
+        let widget = BinaryDataWidget::new(&data).bytes_per_row(16);
+        let expected = Buffer::with_lines([
+            " 0: 4445 4647 DEFG ",
+            "10: 5455 5657 TUVW ",
+            "     █████         ",
+        ]);
+        render_widget(19, 3, widget, state, &expected);
+    }
+
+    #[test]
+    fn scrolled_horizontally_unaligned() {
+        let data: Vec<u8> = (0x40..0x60).collect();
+        let mut state = BinaryDataWidgetState::new();
+        state.scroll_right(1);
+        let widget = BinaryDataWidget::new(&data)
+            .bytes_per_row(16)
+            .show_header(true);
+        let area = Rect::new(0, 0, 19, 4);
+        let mut buffer = Buffer::empty(area);
+        StatefulWidget::render(widget.clone(), area, &mut buffer, &mut state);
+        assert_eq!(state.clicked_address(4, 1), Some(1));
+        assert_eq!(state.clicked_address(7, 1), Some(2));
+        assert_eq!(state.clicked_address(9, 1), Some(3));
+        assert_eq!(state.cell_rect(3), Some(Rect::new(9, 1, 2, 1)));
+        // The cut off group of column 0 and 1 takes a gap, so only 3 bytes fit
+        let expected = Buffer::with_lines([
+            "     1  2 3        ",
+            " 0: 41 4243   ABC  ",
+            "10: 51 5253   QRS  ",
+            " ████              ",
+        ]);
+        render_widget(19, 4, widget, state, &expected);
+    }
+
+    #[test]
+    fn reversed_groups_scrolled_horizontally_unaligned() {
+        let data: Vec<u8> = (0x40..0x60).collect();
+        let mut state = BinaryDataWidgetState::new();
+        state.scroll_right(1);
+        let widget = BinaryDataWidget::new(&data)
+            .bytes_per_row(16)
+            .show_header(true)
+            .byte_order(ByteOrder::ReversedGroups);
+        let area = Rect::new(0, 0, 19, 4);
+        let mut buffer = Buffer::empty(area);
+        StatefulWidget::render(widget.clone(), area, &mut buffer, &mut state);
+        assert_eq!(state.clicked_address(4, 1), Some(1));
+        assert_eq!(state.clicked_address(7, 1), Some(3));
+        assert_eq!(state.clicked_address(9, 1), Some(2));
+        assert_eq!(state.cell_rect(3), Some(Rect::new(7, 1, 2, 1)));
+        // The cut off group of column 0 and 1 keeps its only visible byte in place
+        let expected = Buffer::with_lines([
+            "     1  3 2        ",
+            " 0: 41 4342   ABC  ",
+            "10: 51 5352   QRS  ",
+            " ████              ",
+        ]);
+        render_widget(19, 4, widget, state, &expected);
+    }
+
+    #[test]
+    fn crosshair() {
+        const SELECTED: Style = Style::new().fg(Color::Green);
+        const CROSSHAIR: Style = Style::new().bg(Color::DarkGray);
+
+        let data: Vec<u8> = (0..=0x12).collect();
+        let mut state = BinaryDataWidgetState::new();
+        state.select_address(Some(6));
+        let area = Rect::new(0, 0, 19, 5);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(&data)
+            .highlight_style(SELECTED)
+            .crosshair_style(CROSSHAIR);
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+
+        for (address, value) in data.iter().enumerate() {
+            let row = u16::try_from(address / 4).unwrap();
+            let index = u16::try_from(address % 4).unwrap();
+            let x_hex = 4 + index * 2 + index / 2;
+            let x_char = 14 + index;
+            let on_crosshair = address != 6 && (address / 4 == 1 || address % 4 == 2);
+            for x in [x_hex, x_hex + 1, x_char] {
+                let style = buffer.get(x, row).style();
+                assert_eq!(
+                    style.bg == Some(Color::DarkGray),
+                    on_crosshair,
+                    "address {address} x {x}"
+                );
+            }
+            let fg = if address == 6 {
+                Some(Color::Green)
+            } else {
+                color(*value as char).fg
+            };
+            assert_eq!(buffer.get(x_char, row).style().fg, fg);
+        }
+    }
+
+    #[test]
+    fn horizontal_scrollbar() {
+        let data: Vec<u8> = (0x40..0x60).collect();
+        let mut state = BinaryDataWidgetState::new();
+        state.scroll_right(12);
+        let widget = BinaryDataWidget::new(&data).bytes_per_row(16);
+        let expected = Buffer::with_lines([
+            " 0: 4c4d 4e4f LMNO ",
+            "10: 5c5d 5e5f \\]^_ ",
+            "                   ",
+            "              █████",
+        ]);
+        render_widget(19, 4, widget.clone(), state.clone(), &expected);
+
+        let widget = widget.horizontal_scrollbar(None);
+        let expected = Buffer::with_lines([
+            " 0: 4c4d 4e4f LMNO ",
+            "10: 5c5d 5e5f \\]^_ ",
+            "                   ",
+            "                   ",
+        ]);
+        render_widget(19, 4, widget, state, &expected);
+    }
+
+    #[test]
+    fn uppercase() {
+        let data: Vec<u8> = [0xab, 0xcd].into_iter().cycle().take(0x12).collect();
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&data).uppercase(true);
+        let expected = Buffer::with_lines([
+            " 0: ABCD ABCD ···· ",
+            " 4: ABCD ABCD ···· ",
+            " 8: ABCD ABCD ···· ",
+            " C: ABCD ABCD ···· ",
+            "10: ABCD      ··   ",
+        ]);
+        render_widget(19, 5, widget, state, &expected);
+    }
+
+    #[test]
+    fn decimal_addresses() {
+        let data: Vec<u8> = (0..=255).cycle().take(300).collect();
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&data).address_format(AddressFormat::Decimal);
+        let expected = Buffer::with_lines([
+            "  0:  0 1  2 3 ····",
+            "  4:  4 5  6 7 ····",
+            "  8:  8 9  a b ····",
+        ]);
+        render_widget(19, 3, widget, state, &expected);
+    }
+
+    #[test]
+    fn decimal_addresses_end() {
+        let data: Vec<u8> = (0..=255).cycle().take(300).collect();
+        let mut state = BinaryDataWidgetState::new();
+        state.offset_address = 288;
+        let widget = BinaryDataWidget::new(&data).address_format(AddressFormat::Decimal);
+        let expected = Buffer::with_lines([
+            "288: 2021 2223  !\"#",
+            "292: 2425 2627 $%&'",
+            "296: 2829 2a2b ()*+",
+        ]);
+        render_widget(19, 3, widget, state, &expected);
+    }
+
+    #[test]
+    fn header() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&data).show_header(true);
+        let expected = Buffer::with_lines([
+            "     0 1  2 3      ",
+            " 0:  0 1  2 3 ····█",
+            " 4:  4 5  6 7 ····█",
+            " 8:  8 9  a b ···· ",
+        ]);
+        render_widget(19, 4, widget, state, &expected);
+    }
+
+    #[test]
+    fn without_scrollbar() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&data).scrollbar(None);
+        let expected = Buffer::with_lines([
+            " 0:  0 1  2 3 ···· ",
+            " 4:  4 5  6 7 ···· ",
+            " 8:  8 9  a b ···· ",
+        ]);
+        render_widget(19, 3, widget, state, &expected);
+    }
+
+    #[test]
+    fn address_style() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let mut state = BinaryDataWidgetState::new();
+        let area = Rect::new(0, 0, 19, 3);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(&data)
+            .style(Style::new().bg(Color::Blue))
+            .address_style(Style::new().fg(Color::Red));
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+
+        let mut expected = Buffer::with_lines([
+            " 0:  0 1  2 3 ····█",
+            " 4:  4 5  6 7 ····█",
+            " 8:  8 9  a b ···· ",
+        ]);
+        expected.set_style(area, Style::new().bg(Color::Blue));
+        for y in 0..3 {
+            expected.set_style(Rect::new(0, y, 4, 1), Style::new().fg(Color::Red));
+        }
+        for y in 0..3 {
+            for x in 0..4 {
+                assert_eq!(buffer.get(x, y), expected.get(x, y), "x {x} y {y}");
+            }
+        }
+    }
+
+    #[test]
+    fn row_stripe() {
+        const SELECTED: Style = Style::new().fg(Color::Green);
+
+        let data: Vec<u8> = (0..=0x12).collect();
+        let mut state = BinaryDataWidgetState::new();
+        state.select_address(Some(5));
+        let area = Rect::new(0, 0, 19, 4);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(&data)
+            .highlight_style(SELECTED)
+            .row_stripe_style(Style::new().bg(Color::DarkGray));
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+
+        for y in 0..4 {
+            for x in 0..18 {
+                let striped = buffer.get(x, y).style().bg == Some(Color::DarkGray);
+                assert_eq!(striped, y % 2 == 0, "x {x} y {y}");
+            }
+        }
+        assert_eq!(buffer.get(0, 0).style().fg, Some(Color::Cyan));
+        assert_eq!(buffer.get(14, 0).style().fg, color('\0').fg);
+        assert_eq!(buffer.get(6, 1).style().fg, Some(Color::Green));
+    }
+
+    #[test]
+    fn strings() {
+        const STRING: Style = Style::new().bg(Color::Blue);
+
+        let data = b"\0ab\0\0Hello!\0\0xyz\0\0\0";
+        let mut state = BinaryDataWidgetState::new();
+        let area = Rect::new(0, 0, 19, 5);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(data)
+            .min_string_len(4)
+            .string_style(STRING);
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+
+        for (address, value) in data.iter().enumerate() {
+            let row = u16::try_from(address / 4).unwrap();
+            let index = u16::try_from(address % 4).unwrap();
+            let char_cell = buffer.get(14 + index, row).style();
+            let in_string = (5..11).contains(&address);
+            assert_eq!(
+                char_cell.bg == Some(Color::Blue),
+                in_string,
+                "address {address}"
+            );
+            assert_eq!(char_cell.fg, color(*value as char).fg);
+            let hex_cell = buffer.get(4 + index * 2 + index / 2, row).style();
+            assert_ne!(hex_cell.bg, Some(Color::Blue));
+        }
+    }
+
+    #[test]
+    fn row_marker() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&data)
+            .row_marker_fn(|address| (address == 4).then_some(('>', Style::new())));
+        let expected = Buffer::with_lines([
+            " 0:   0 1  2 3 ····█",
+            " 4: > 4 5  6 7 ····█",
+            " 8:   8 9  a b ···· ",
+        ]);
+        render_widget(20, 3, widget, state, &expected);
+    }
+
+    #[test]
+    fn relative_addresses() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let mut state = BinaryDataWidgetState::new();
+        state.select_address(Some(8));
+        let widget =
+            BinaryDataWidget::new(&data).address_origin(AddressOrigin::RelativeToSelection);
+        let expected = Buffer::with_lines([
+            " -8:  0 1  2 3 ····",
+            " -4:  4 5  6 7 ····",
+            " +0:  8 9  a b ····",
+            " +4:  c d  e f ····",
+            " +8: 1011 12   ··· ",
+        ]);
+        render_widget(19, 5, widget, state, &expected);
+    }
+
+    #[test]
+    fn empty_message() {
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&[]).empty_message("no data");
+        let expected = Buffer::with_lines([
+            "                   ",
+            "      no data      ",
+            "                   ",
+        ]);
+        render_widget(19, 3, widget, state, &expected);
+    }
+
+    #[test]
+    fn too_small_hint() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&data).show_too_small_hint(true);
+        let expected = Buffer::with_lines(["     ", "  …  ", "     "]);
+        render_widget(5, 3, widget, state, &expected);
+    }
+
+    #[test]
+    fn selected_nibble() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let mut state = BinaryDataWidgetState::new();
+        state.set_nibble_navigation(true);
+        state.select_address(Some(1));
+        state.key_right();
+        let area = Rect::new(0, 0, 19, 3);
+        let mut buffer = Buffer::empty(area);
+        StatefulWidget::render(BinaryDataWidget::new(&data), area, &mut buffer, &mut state);
+
+        let underlined = |x| {
+            buffer
+                .get(x, 0)
+                .style()
+                .add_modifier
+                .contains(Modifier::UNDERLINED)
+        };
+        assert!(!underlined(6));
+        assert!(underlined(7));
+        assert!(!underlined(15));
+    }
+
+    #[test]
+    fn column_rule() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&data)
+            .bytes_per_row(8)
+            .column_rule_every(4);
+        let expected = Buffer::with_lines([
+            " 0:  0 1  2 3│ 4 5  6 7 ········",
+            " 8:  8 9  a b│ c d  e f ········",
+            "10: 1011 12             ···     ",
+        ]);
+        render_widget(32, 3, widget, state, &expected);
+    }
+
+    #[test]
+    fn cursor_style() {
+        const SELECTED: Style = Style::new().fg(Color::Green);
+        const CURSOR: Style = Style::new().fg(Color::Yellow);
+
+        let data: Vec<u8> = (0..=0x12).collect();
+        let mut state = BinaryDataWidgetState::new();
+        state.select_address(Some(3));
+        state.set_anchor();
+        state.select_address(Some(5));
+        let area = Rect::new(0, 0, 19, 3);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(&data)
+            .highlight_style(SELECTED)
+            .cursor_style(CURSOR);
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+
+        assert_eq!(buffer.get(17, 0).style().fg, Some(Color::Green));
+        assert_eq!(buffer.get(14, 1).style().fg, Some(Color::Green));
+        assert_eq!(buffer.get(15, 1).style().fg, Some(Color::Yellow));
+        assert_eq!(buffer.get(6, 1).style().fg, Some(Color::Yellow));
+        assert_eq!(buffer.get(16, 1).style().fg, color('\x06').fg);
+    }
+
+    #[test]
+    fn pad_last_row() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let widget = BinaryDataWidget::new(&data);
+        let expected = Buffer::with_lines([
+            " 0:  0 1  2 3 ···· ",
+            " 4:  4 5  6 7 ···· ",
+            " 8:  8 9  a b ···· ",
+            " c:  c d  e f ···· ",
+            "10: 1011 12   ···  ",
+        ]);
+        render_widget(
+            19,
+            5,
+            widget.clone(),
+            BinaryDataWidgetState::new(),
+            &expected,
+        );
+
+        let widget = widget.pad_last_row_symbol(Some('.'));
+        let expected = Buffer::with_lines([
+            " 0:  0 1  2 3 ···· ",
+            " 4:  4 5  6 7 ···· ",
+            " 8:  8 9  a b ···· ",
+            " c:  c d  e f ···· ",
+            "10: 1011 12.. ···. ",
+        ]);
+        render_widget(19, 5, widget, BinaryDataWidgetState::new(), &expected);
+    }
+
+    #[test]
+    fn checksum_footer() {
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(b"123456789").show_checksum(true);
+        let expected = Buffer::with_lines([
+            "0: 3132 3334 1234 █",
+            "4: 3536 3738 5678 █",
+            "CRC32: cbf43926    ",
+        ]);
+        render_widget(19, 3, widget, state, &expected);
+    }
+
+    #[test]
+    fn detail_line() {
+        let mut state = BinaryDataWidgetState::new();
+        state.select_address(Some(1));
+        let widget = BinaryDataWidget::new(b"ZA\x00")
+            .show_detail_line(true)
+            .show_checksum(true);
+        let expected = Buffer::with_lines([
+            "0: 5a41  0   ZA·   ",
+            "CRC32: 77080c30    ",
+            "0x41 65 'A' 0b01000",
+        ]);
+        render_widget(19, 3, widget, state, &expected);
+    }
+
+    #[test]
+    fn detail_line_without_selection() {
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(b"ZA\x00").show_detail_line(true);
+        let expected = Buffer::with_lines(["0: 5a41  0   ZA·   ", "                   "]);
+        render_widget(19, 2, widget, state, &expected);
+    }
+
+    #[test]
+    fn search_highlight() {
+        const MATCH: Style = Style::new().bg(Color::Yellow);
+
+        let data = b"abcabxab\0ab";
+        let mut state = BinaryDataWidgetState::new();
+        let area = Rect::new(0, 0, 19, 2);
+        let mut buffer = Buffer::empty(area);
+        StatefulWidget::render(BinaryDataWidget::new(data), area, &mut buffer, &mut state);
+        state.scroll_down(1);
+
+        let widget = BinaryDataWidget::new(data)
+            .search_highlight(b"ab")
+            .search_style(MATCH);
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+        assert_eq!(state.get_offset_address(), 4);
+
+        let highlighted = (4..data.len())
+            .map(|address| {
+                let row = u16::try_from(address / 4 - 1).unwrap();
+                let index = u16::try_from(address % 4).unwrap();
+                let hex = buffer.get(3 + index * 2 + index / 2, row).style().bg;
+                let char = buffer.get(13 + index, row).style().bg;
+                assert_eq!(hex, char, "address {address}");
+                char == Some(Color::Yellow)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(highlighted, [true, false, true, true, false, true, true]);
+    }
+
+    #[test]
+    fn header_click() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let mut state = BinaryDataWidgetState::new();
+        let area = Rect::new(0, 0, 19, 4);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(&data).show_header(true);
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+        assert_eq!(state.clicked_address(14, 1), Some(0));
+        assert_eq!(state.clicked_address(15, 2), Some(5));
+    }
+
+    #[test]
+    fn without_char_column() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&data).show_char_column(false);
+        let expected = Buffer::with_lines([
+            " 0:  0 1  2 3  4 5  6 7 ",
+            " 8:  8 9  a b  c d  e f ",
+            "10: 1011 12             ",
+        ]);
+        render_widget(24, 3, widget, state, &expected);
+    }
+
+    #[test]
+    fn only_char_column() {
+        let data = b"Hello world! This is a text.";
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(data).show_hex_column(false);
+        let expected = Buffer::with_lines([
+            " 0: Hello wo ",
+            " 8: rld! Thi ",
+            "10: s is a t ",
+            "18: ext.     ",
+        ]);
+        render_widget(13, 4, widget, state, &expected);
+    }
+
+    #[test]
+    fn only_char_column_header() {
+        let data = b"Hello world! This is a text.";
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(data)
+            .show_hex_column(false)
+            .show_header(true);
+        let expected = Buffer::with_lines(["    01234567 ", " 0: Hello wo█", " 8: rld! Thi "]);
+        render_widget(13, 3, widget, state, &expected);
+    }
+
+    #[test]
+    fn control_pictures() {
+        let data = b"\t\n\r\x7f";
+        let mut state = BinaryDataWidgetState::new();
+        let area = Rect::new(0, 0, 19, 1);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(data).control_pictures(true);
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+        let expected = Buffer::with_lines(["0:  9 a  d7f ␉␊␍·  "]);
+        let mut buffer_without_styles = buffer.clone();
+        buffer_without_styles.set_style(area, Style::reset());
+        assert_eq!(buffer_without_styles, expected);
+        assert_eq!(buffer.get(13, 0).style().fg, color('\t').fg);
+    }
+
+    #[test]
+    fn custom_layout() {
+        let data = (0..=0x12).collect::<Vec<u8>>();
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&data).layout(HexLayout {
+            address_suffix: "|",
+            group_gap: 0,
+            char_gap: 3,
+            group_size: 4,
+        });
+        let expected = Buffer::with_lines([
+            " 0| 0 1 2 3   ····█",
+            " 4| 4 5 6 7   ····█",
+            " 8| 8 9 a b   ····█",
+            " c| c d e f   ····█",
+        ]);
+        render_widget(19, 4, widget, state, &expected);
+    }
+
+    #[test]
+    fn hex_char_separator() {
+        let data = (0..=0x12).collect::<Vec<u8>>();
+        let mut state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&data)
+            .hex_char_gap(3)
+            .hex_char_separator('|');
+        let expected = Buffer::with_lines([
+            " 0:  0 1  2 3 | ····█",
+            " 4:  4 5  6 7 | ····█",
+            " 8:  8 9  a b | ····█",
+            " c:  c d  e f | ····█",
+        ]);
+        let area = Rect::new(0, 0, 21, 4);
+        let mut buffer = Buffer::empty(area);
+        StatefulWidget::render(widget.clone(), area, &mut buffer, &mut state);
+        assert_eq!(state.clicked_address(16, 1), Some(4));
+        assert_eq!(state.clicked_address(19, 1), Some(7));
+        render_widget(21, 4, widget, state, &expected);
+    }
+
+    #[test]
+    fn row_spacing() {
+        let data = (0..=0x12).collect::<Vec<u8>>();
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&data).row_spacing(1);
+        let expected = Buffer::with_lines([
+            " 0:  0 1  2 3 ····█",
+            "                  █",
+            " 4:  4 5  6 7 ····█",
+            "                  █",
+            " 8:  8 9  a b ···· ",
+        ]);
+        render_widget(19, 5, widget, state, &expected);
+    }
+
+    #[test]
+    fn row_spacing_click() {
+        let data = (0..=0x12).collect::<Vec<u8>>();
+        let mut state = BinaryDataWidgetState::new();
+        let area = Rect::new(0, 0, 19, 5);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(&data).row_spacing(1);
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+        assert_eq!(state.clicked_address(4, 2), Some(4));
+        assert_eq!(state.clicked_address(4, 3), Some(4));
+        assert_eq!(state.clicked_address(4, 4), Some(8));
+        assert_eq!(state.cell_rect(9), Some(Rect::new(6, 4, 2, 1)));
+    }
+
+    #[test]
+    fn address_position_right() {
+        let data = (0..=0x12).collect::<Vec<u8>>();
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&data).address_position(AddressPosition::Right);
+        let expected = Buffer::with_lines([
+            " 0 1  2 3 ····  0 █",
+            " 4 5  6 7 ····  4 █",
+            " 8 9  a b ····  8  ",
+        ]);
+        render_widget(19, 3, widget, state, &expected);
+    }
+
+    #[test]
+    fn address_position_both() {
+        let data = (0..=0x12).collect::<Vec<u8>>();
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&data).address_position(AddressPosition::Both);
+        let expected = Buffer::with_lines([
+            " 0:  0 1  2 3 ····  0 █",
+            " 4:  4 5  6 7 ····  4 █",
+            " 8:  8 9  a b ····  8  ",
+        ]);
+        render_widget(23, 3, widget, state, &expected);
+    }
+
+    #[test]
+    fn address_position_right_click() {
+        let data = (0..=0x12).collect::<Vec<u8>>();
+        let mut state = BinaryDataWidgetState::new();
+        let area = Rect::new(0, 0, 19, 3);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(&data).address_position(AddressPosition::Right);
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+        assert_eq!(state.clicked_address(0, 1), Some(4));
+        assert_eq!(state.clicked_address(13, 1), Some(7));
+        assert_eq!(state.clicked_address(16, 1), None);
+    }
+
+    #[test]
+    fn word_view_little_endian() {
+        let data = [
+            0xef, 0xbe, 0xad, 0xde, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x34, 0x12,
+        ];
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&data)
+            .word_size(Some(4))
+            .byte_order(ByteOrder::ReversedGroups);
+        let expected = Buffer::with_lines([
+            "0: deadbeef        1 ········ ",
+            "8:        0     1234 ····4·   ",
+        ]);
+        render_widget(30, 2, widget, state, &expected);
+    }
+
+    #[test]
+    fn word_view_click_selects_first_byte() {
+        let data = (0..=0x12).collect::<Vec<u8>>();
+        let mut state = BinaryDataWidgetState::new();
+        let area = Rect::new(0, 0, 30, 3);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(&data).word_size(Some(4));
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+        assert!(state.select_at(19, 1));
+        assert_eq!(state.selected_address(), Some(0x0c));
+    }
+
+    #[test]
+    fn word_view_scrolled_horizontally_unaligned() {
+        let data: Vec<u8> = (0..0x20).map(|i| if i % 2 == 0 { 0 } else { i }).collect();
+        let mut state = BinaryDataWidgetState::new();
+        state.scroll_right(1);
+        let widget = BinaryDataWidget::new(&data)
+            .bytes_per_row(16)
+            .word_size(Some(2));
+        let area = Rect::new(0, 0, 19, 3);
+        let mut buffer = Buffer::empty(area);
+        StatefulWidget::render(widget.clone(), area, &mut buffer, &mut state);
+        assert_eq!(state.clicked_address(4, 0), Some(1));
+        assert_eq!(state.clicked_address(9, 0), Some(2));
+        // The word of column 0 and 1 is cut off, so its visible byte is shown on its own
+        let expected = Buffer::with_lines([
+            " 0:  1    3   ···  ",
+            "10: 11   13   ···  ",
+            " ████              ",
+        ]);
+        render_widget(19, 3, widget, state, &expected);
+    }
+
+    #[test]
+    fn byte_order_reversed_groups() {
+        let data = (0..=0x12).collect::<Vec<u8>>();
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&data)
+            .group_size(4)
+            .byte_order(ByteOrder::ReversedGroups);
+        let expected = Buffer::with_lines([
+            " 0:  3 2 1 0 ···· ",
+            " 4:  7 6 5 4 ···· ",
+            " 8:  b a 9 8 ···· ",
+            " c:  f e d c ···· ",
+            "10:   121110 ···  ",
+        ]);
+        render_widget(18, 5, widget, state, &expected);
+    }
+
+    #[test]
+    fn non_printable_symbol() {
+        let data = b"ab\x00\x7f cd\xff";
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(data).non_printable_symbol('.');
+        let expected = Buffer::with_lines(["0: 6162  07f ab..  ", "4: 2063 64ff  cd.  "]);
+        render_widget(19, 2, widget, state, &expected);
+    }
+
+    #[test]
+    fn group_separator() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&data)
+            .group_size(4)
+            .group_separator('|');
+        let expected = Buffer::with_lines([
+            " 0:  0 1 2 3| 4 5 6 7 ········",
+            " 8:  8 9 a b| c d e f ········",
+            "10: 101112            ···     ",
+        ]);
+        render_widget(30, 3, widget, state, &expected);
+    }
+
+    #[test]
+    fn binary_byte_format() {
+        let data = [0b1011_0100, 0x0f, 0xff, 0, 0x41];
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&data).byte_format(ByteFormat::Binary);
+        let expected = Buffer::with_lines([
+            "0: 1011010000001111 1111111100000000 ····",
+            "4: 01000001                          A   ",
+        ]);
+        render_widget(41, 2, widget, state, &expected);
+    }
+
+    #[test]
+    fn base_address() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let mut state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&data).base_address(0x1000);
+        let expected = Buffer::with_lines([
+            "1000:  0 1  2 3 ···· ",
+            "1004:  4 5  6 7 ···· ",
+            "1008:  8 9  a b ···· ",
+            "100c:  c d  e f ···· ",
+            "1010: 1011 12   ···  ",
+        ]);
+        let area = Rect::new(0, 0, 21, 5);
+        let mut buffer = Buffer::empty(area);
+        StatefulWidget::render(widget.clone(), area, &mut buffer, &mut state);
+        assert_eq!(state.clicked_address(6, 1), Some(4));
+        render_widget(21, 5, widget, state, &expected);
+    }
+
+    #[test]
+    fn data_range() {
+        let data: Vec<u8> = (0..=255).cycle().take(512).collect();
+        let mut state = BinaryDataWidgetState::new();
+        state.select_address(Some(0x100));
+        let widget = BinaryDataWidget::new(&data).data_range(0x110..0x122);
+        let expected = Buffer::with_lines([
+            "110: 1011 1213 ···· ",
+            "114: 1415 1617 ···· ",
+            "118: 1819 1a1b ···· ",
+            "11c: 1c1d 1e1f ···· ",
+            "120: 2021       !   ",
+        ]);
+        let area = Rect::new(0, 0, 20, 5);
+        let mut buffer = Buffer::empty(area);
+        StatefulWidget::render(widget.clone(), area, &mut buffer, &mut state);
+        assert_eq!(state.selected_address(), Some(0x11));
+        assert_eq!(state.clicked_address(5, 1), Some(4));
+        render_widget(20, 5, widget, state, &expected);
+    }
+
+    #[test]
+    fn data_range_callbacks_get_addresses_within_the_whole_data() {
+        const HEADER: Style = Style::new().fg(Color::Red);
+
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let mut state = BinaryDataWidgetState::new();
+        let area = Rect::new(0, 0, 20, 2);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(&data)
+            .data_range(0x10..0x18)
+            .byte_style_fn(|address, _value| {
+                if address == 0x11 {
+                    HEADER
+                } else {
+                    Style::new()
+                }
+            })
+            .row_marker_fn(|address| (address == 0x14).then_some(('>', Style::new())));
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+
+        let expected = Buffer::with_lines(["10:  1011 1213 ···· ", "14: >1415 1617 ···· "]);
+        assert_eq!(buffer.get(5, 0).style().fg, Some(Color::Reset));
+        assert_eq!(buffer.get(7, 0).style().fg, Some(Color::Red));
+        assert_eq!(buffer.get(8, 0).style().fg, Some(Color::Red));
+        for y in 0..2 {
+            for x in 0..20 {
+                assert_eq!(buffer.get(x, y).symbol(), expected.get(x, y).symbol());
+            }
+        }
+    }
+
+    #[test]
+    fn minimap() {
+        let mut data = vec![0; 0x40];
+        data[0x10..0x20].fill(b'A');
+        data[0x28..0x30].fill(b'B');
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&data).show_minimap(true);
+        let expected = Buffer::with_lines([
+            " 0:  0 0  0 0 ····  █",
+            " 4:  0 0  0 0 ···· █ ",
+            " 8:  0 0  0 0 ···· ▒ ",
+            " c:  0 0  0 0 ····   ",
+        ]);
+        render_widget(21, 4, widget, state, &expected);
+    }
+
+    #[test]
+    fn selected_char_override() {
+        let data: Vec<u8> = ('A'..='H').map(|char| char as u8).collect();
+        let mut state = BinaryDataWidgetState::new();
+        state.select_address(Some(5));
+        let widget = BinaryDataWidget::new(&data).selected_char_override(Some('#'));
+        let expected = Buffer::with_lines(["0: 4142 4344 ABCD ", "4: 4546 4748 E#GH "]);
+        let area = Rect::new(0, 0, 18, 2);
+        let mut buffer = Buffer::empty(area);
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+        buffer.set_style(area, Style::reset());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn len_and_last_address() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let widget = BinaryDataWidget::new(&data);
+        assert_eq!(widget.len(), 0x13);
+        assert!(!widget.is_empty());
+        assert_eq!(widget.last_address(), Some(0x12));
+
+        let widget = widget.data_range(4..8);
+        assert_eq!(widget.len(), 4);
+        assert_eq!(widget.last_address(), Some(3));
+
+        let widget = BinaryDataWidget::new(&[]);
+        assert_eq!(widget.len(), 0);
+        assert!(widget.is_empty());
+        assert_eq!(widget.last_address(), None);
+    }
+
+    #[test]
+    fn pad_char_column() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let filled = Buffer::with_lines(["xxxxxxxxxxxxxxxxxxx"; 5]);
+        let area = filled.area;
+
+        let mut buffer = filled.clone();
+        let widget = BinaryDataWidget::new(&data);
+        StatefulWidget::render(widget, area, &mut buffer, &mut BinaryDataWidgetState::new());
+        assert_eq!(buffer.get(17, 4).symbol(), "x");
+
+        let mut buffer = filled;
+        let widget = BinaryDataWidget::new(&data).pad_char_column(true);
+        StatefulWidget::render(widget, area, &mut buffer, &mut BinaryDataWidgetState::new());
+        buffer.set_style(area, Style::reset());
+        let expected = Buffer::with_lines([
+            " 0:  0 1  2 3x····x",
+            " 4:  4 5  6 7x····x",
+            " 8:  8 9  a bx····x",
+            " c:  c d  e fx····x",
+            "10: 1011 12xxx··· x",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn byte_style_fn() {
+        const HEADER: Style = Style::new().fg(Color::Red);
+        const PAYLOAD: Style = Style::new().fg(Color::Blue);
+        const SELECTED: Style = Style::new().fg(Color::Green);
+
+        let data = b"Hello";
+        let mut state = BinaryDataWidgetState::new();
+        state.select_address(Some(4));
+        let area = Rect::new(0, 0, 19, 2);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(data)
+            .highlight_style(SELECTED)
+            .byte_style_fn(|address, _value| if address < 2 { HEADER } else { PAYLOAD });
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+
+        assert_eq!(buffer.get(3, 0).style().fg, Some(Color::Red));
+        assert_eq!(buffer.get(13, 0).style().fg, Some(Color::Red));
+        assert_eq!(buffer.get(8, 0).style().fg, Some(Color::Blue));
+        assert_eq!(buffer.get(15, 0).style().fg, Some(Color::Blue));
+        assert_eq!(buffer.get(3, 1).style().fg, Some(Color::Green));
+        assert_eq!(buffer.get(13, 1).style().fg, Some(Color::Green));
+    }
+
+    #[test]
+    fn hover_style() {
+        const HOVER: Style = Style::new().fg(Color::Magenta);
+        const SELECTED: Style = Style::new().fg(Color::Green);
+
+        let data = b"Hello";
+        let mut state = BinaryDataWidgetState::new();
+        let area = Rect::new(0, 0, 19, 2);
+        let mut buffer = Buffer::empty(area);
+        let widget = || {
+            BinaryDataWidget::new(data)
+                .highlight_style(SELECTED)
+                .hover_style(HOVER)
+        };
+        StatefulWidget::render(widget(), area, &mut buffer, &mut state);
+        assert!(state.hover_at(6, 0));
+        state.select_address(Some(0));
+        StatefulWidget::render(widget(), area, &mut buffer, &mut state);
+        assert_eq!(buffer.get(6, 0).style().fg, Some(Color::Magenta));
+        assert_eq!(buffer.get(14, 0).style().fg, Some(Color::Magenta));
+        assert_eq!(buffer.get(3, 0).style().fg, Some(Color::Green));
+        assert_eq!(buffer.get(8, 0).style().fg, color('l').fg);
+
+        assert!(state.hover_at(3, 0));
+        StatefulWidget::render(widget(), area, &mut buffer, &mut state);
+        assert_eq!(buffer.get(3, 0).style().fg, Some(Color::Green));
+
+        assert!(state.hover_at(100, 100));
+        assert_eq!(state.hovered_address(), None);
+    }
+
+    #[test]
+    fn color_scheme() {
+        let data = b"\0A";
+        let mut state = BinaryDataWidgetState::new();
+        let area = Rect::new(0, 0, 19, 1);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(data).color_scheme(ColorScheme {
+            null: Style::new().fg(Color::White),
+            ..ColorScheme::default()
+        });
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+        assert_eq!(buffer.get(13, 0).symbol(), "·");
+        assert_eq!(buffer.get(13, 0).style().fg, Some(Color::White));
+        assert_eq!(buffer.get(14, 0).symbol(), "A");
+        assert_eq!(buffer.get(14, 0).style().fg, color('A').fg);
+    }
+
+    #[test]
+    fn light_color_scheme() {
+        let data = b"\x01";
+        let mut state = BinaryDataWidgetState::new();
+        let area = Rect::new(0, 0, 19, 1);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(data).color_scheme(ColorScheme::light());
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+        assert_eq!(buffer.get(4, 0).style().fg, Some(Color::Magenta));
+        assert_ne!(buffer.get(4, 0).style().fg, color('\x01').fg);
+    }
+
+    #[test]
+    fn null_and_fill_byte_style() {
+        let data = b"\0\xffA";
+        let mut state = BinaryDataWidgetState::new();
+        let area = Rect::new(0, 0, 19, 1);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(data)
+            .null_byte_style(Some(Style::new()))
+            .fill_byte_style(Some(Style::new().fg(Color::Magenta)));
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+        assert_eq!(buffer.get(13, 0).style().fg, Some(Color::Reset));
+        assert_eq!(buffer.get(14, 0).style().fg, Some(Color::Magenta));
+        assert_eq!(buffer.get(15, 0).style().fg, color('A').fg);
+    }
+
+    #[test]
+    fn null_byte_style_falls_back() {
+        let data = b"\0";
+        let mut state = BinaryDataWidgetState::new();
+        let area = Rect::new(0, 0, 19, 1);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(data).null_byte_style(None);
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+        assert_eq!(buffer.get(13, 0).style().fg, color('\0').fg);
+    }
+
+    #[test]
+    fn selected_range() {
+        const SELECTED: Style = Style::new().fg(Color::Green);
+
+        let data: Vec<u8> = (0..=0x12).collect();
+        let mut state = BinaryDataWidgetState::new();
+        state.select_address(Some(3));
+        state.set_anchor();
+        state.select_address(Some(5));
+        let area = Rect::new(0, 0, 19, 3);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(&data).highlight_style(SELECTED);
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+
+        let selected_chars = (14..18)
+            .map(|x| buffer.get(x, 0).style().fg == Some(Color::Green))
+            .chain((14..18).map(|x| buffer.get(x, 1).style().fg == Some(Color::Green)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            selected_chars,
+            [false, false, false, true, true, true, false, false]
+        );
+    }
+
+    #[test]
+    fn selected_range_over_rows_is_contiguous() {
+        const SELECTED: Style = Style::new().fg(Color::Green);
+
+        let data: Vec<u8> = (0..=0x12).collect();
+        let mut state = BinaryDataWidgetState::new();
+        state.select_address(Some(3));
+        state.set_anchor();
+        state.select_address(Some(9));
+        let area = Rect::new(0, 0, 19, 3);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(&data).highlight_style(SELECTED);
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+
+        let is_selected = |x: u16, y: u16| buffer.get(x, y).style().fg == Some(Color::Green);
+        let hex_row = |y: u16| (4..13).map(|x| is_selected(x, y)).collect::<Vec<_>>();
+        let char_row = |y: u16| (14..18).map(|x| is_selected(x, y)).collect::<Vec<_>>();
+        assert_eq!(
+            hex_row(0),
+            [false, false, false, false, false, false, false, true, true]
+        );
+        assert_eq!(hex_row(1), [true; 9]);
+        assert_eq!(
+            hex_row(2),
+            [true, true, true, true, false, false, false, false, false]
+        );
+        assert_eq!(char_row(0), [false, false, false, true]);
+        assert_eq!(char_row(1), [true; 4]);
+        assert_eq!(char_row(2), [true, true, false, false]);
+    }
+
+    #[test]
+    fn dim_unselected() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let mut state = BinaryDataWidgetState::new();
+        state.select_address(Some(1));
+        state.set_anchor();
+        state.select_address(Some(2));
+        let area = Rect::new(0, 0, 19, 2);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(&data).dim_unselected(true);
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+
+        let is_dim = |x: u16, y: u16| {
+            buffer
+                .get(x, y)
+                .style()
+                .add_modifier
+                .contains(Modifier::DIM)
+        };
+        let dimmed_chars = (14..18)
+            .map(|x| is_dim(x, 0))
+            .chain((14..18).map(|x| is_dim(x, 1)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            dimmed_chars,
+            [true, false, false, true, true, true, true, true]
+        );
+        assert!(is_dim(5, 0));
+        assert!(!is_dim(7, 0));
+        assert_eq!(buffer.get(14, 1).style().fg, color('\x04').fg);
+    }
+
+    #[test]
+    fn dim_unselected_without_range() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let mut state = BinaryDataWidgetState::new();
+        state.select_address(Some(1));
+        let area = Rect::new(0, 0, 19, 2);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(&data).dim_unselected(true);
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+        for x in 0..area.width {
+            for y in 0..area.height {
+                assert!(!buffer
+                    .get(x, y)
+                    .style()
+                    .add_modifier
+                    .contains(Modifier::DIM));
+            }
+        }
+    }
+
+    #[test]
+    fn center_selection() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let mut state = BinaryDataWidgetState::new();
+        state.select_address(Some(0x20));
+        state.center_selection();
+        let expected = Buffer::with_lines([
+            "18: 1819 1a1b ···· ",
+            "1c: 1c1d 1e1f ···· ",
+            "20: 2021 2223  !\"#█",
+            "24: 2425 2627 $%&'█",
+            "28: 2829 2a2b ()*+ ",
+        ]);
+        render(19, 5, &data, state, &expected);
+    }
+
+    #[test]
+    fn bookmark_style() {
+        const BOOKMARK: Style = Style::new().fg(Color::Magenta);
+        const SELECTED: Style = Style::new().fg(Color::Green);
+
+        let data = b"Hello";
+        let mut state = BinaryDataWidgetState::new();
+        state.add_bookmark(3);
+        state.add_bookmark(4);
+        state.select_address(Some(4));
+        let area = Rect::new(0, 0, 19, 2);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(data)
+            .highlight_style(SELECTED)
+            .bookmark_style(BOOKMARK);
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+
+        assert_eq!(buffer.get(10, 0).style().fg, Some(Color::Magenta));
+        assert_eq!(buffer.get(16, 0).style().fg, Some(Color::Magenta));
+        assert_eq!(buffer.get(15, 0).style().fg, color('l').fg);
+        assert_eq!(buffer.get(3, 1).style().fg, Some(Color::Green));
+        assert_eq!(buffer.get(13, 1).style().fg, Some(Color::Green));
+    }
+
+    #[test]
+    fn char_map() {
+        let mut latin1 = ['.'; 256];
+        for (value, symbol) in latin1.iter_mut().enumerate().skip(0x20) {
+            *symbol = char::from(u8::try_from(value).unwrap());
+        }
+        let data = b"ab\xe4\xfc\x00\xa9";
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(data).char_map(&latin1);
+        let expected = Buffer::with_lines(["0: 6162 e4fc abäü  ", "4:  0a9      .©    "]);
+        render_widget(19, 2, widget, state, &expected);
+    }
+
+    #[test]
+    fn owned_data() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::from_owned(data);
+        let expected = Buffer::with_lines([
+            " 0:  0 1  2 3 ···· ",
+            " 4:  4 5  6 7 ···· ",
+            " 8:  8 9  a b ···· ",
+            " c:  c d  e f ···· ",
+            "10: 1011 12   ···  ",
+        ]);
+        render_widget(19, 5, widget, state, &expected);
+    }
+
+    #[test]
+    fn custom_data_source() {
+        /// Every byte is its address, panics when reading outside of the visible area
+        struct Counting;
+        impl DataSource for Counting {
+            fn len(&self) -> usize {
+                0x1_0000
+            }
+
+            fn byte(&self, address: usize) -> Option<u8> {
+                assert!(address < 0x1000, "only the visible area should be read");
+                u8::try_from(address % 0x100).ok()
+            }
+        }
+
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::from_source(Counting);
+        let expected = Buffer::with_lines([
+            "   0:  0 1  2 3 ····█",
+            "   4:  4 5  6 7 ···· ",
+            "   8:  8 9  a b ···· ",
+        ]);
+        render_widget(21, 3, widget, state, &expected);
+    }
+
+    #[test]
+    fn visible_byte_range() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let area = Rect::new(0, 0, 19, 5);
+        let mut state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&data);
+        assert_eq!(widget.visible_byte_range(area, &state), 0..0x14);
+
+        state.select_address(Some(0x30));
+        assert_eq!(widget.visible_byte_range(area, &state), 0x20..0x34);
+
+        state.scroll_down(100);
+        assert_eq!(widget.visible_byte_range(area, &state), 0x30..0x40);
+        let mut buffer = Buffer::empty(area);
+        StatefulWidget::render(widget.clone(), area, &mut buffer, &mut state);
+        assert_eq!(state.visible_address_range(), Some(0x30..0x40));
+        assert_eq!(widget.visible_byte_range(area, &state), 0x30..0x40);
+    }
+
+    #[test]
+    fn visible_byte_range_with_checksum() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let area = Rect::new(0, 0, 19, 5);
+        let mut state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&data).show_checksum(true);
+        assert_eq!(widget.visible_byte_range(area, &state), 0..0x10);
+        let mut buffer = Buffer::empty(area);
+        StatefulWidget::render(widget.clone(), area, &mut buffer, &mut state);
+        assert_eq!(state.visible_address_range(), Some(0..0x10));
+
+        let single_row = Rect::new(0, 0, 19, 1);
+        assert_eq!(widget.get_max_lines_of_data_in_area(single_row), 0);
+        assert_eq!(widget.get_max_lines_of_data_in_area(area), 16);
+    }
+
+    #[test]
+    fn visible_byte_range_with_detail_line() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let area = Rect::new(0, 0, 19, 5);
+        let mut state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&data)
+            .show_checksum(true)
+            .show_detail_line(true);
+        assert_eq!(widget.visible_byte_range(area, &state), 0..0xc);
+        let mut buffer = Buffer::empty(area);
+        StatefulWidget::render(widget.clone(), area, &mut buffer, &mut state);
+        assert_eq!(state.visible_address_range(), Some(0..0xc));
+        assert_eq!(
+            widget.get_max_lines_of_data_in_area(Rect::new(0, 0, 19, 2)),
+            0
+        );
+    }
+
+    #[test]
+    fn visible_byte_range_with_horizontal_scrollbar() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let area = Rect::new(0, 0, 19, 5);
+        let mut state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(&data).bytes_per_row(8);
+        assert_eq!(widget.visible_byte_range(area, &state), 0..0x20);
+        let mut buffer = Buffer::empty(area);
+        StatefulWidget::render(widget.clone(), area, &mut buffer, &mut state);
+        assert_eq!(state.last_available_height, 4);
+        assert_eq!(widget.visible_byte_range(area, &state), 0..0x20);
+    }
+
+    #[test]
+    fn diff_against() {
+        const DIFF: Style = Style::new().fg(Color::Red);
+
+        let data = b"Hello";
+        let other = b"Hallo";
+        let mut state = BinaryDataWidgetState::new();
+        let area = Rect::new(0, 0, 19, 2);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(data)
+            .diff_against(other)
+            .diff_style(DIFF);
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+
+        let different = (13..17)
+            .map(|x| buffer.get(x, 0).style().fg == Some(Color::Red))
+            .collect::<Vec<_>>();
+        assert_eq!(different, [false, true, false, false]);
+        assert_eq!(buffer.get(5, 0).style().fg, Some(Color::Red));
+        assert_eq!(buffer.get(3, 0).style().fg, color('H').fg);
+    }
+
+    #[test]
+    fn diff_against_shorter() {
+        const DIFF: Style = Style::new().fg(Color::Red);
+
+        let data = b"Hello";
+        let mut state = BinaryDataWidgetState::new();
+        state.select_address(Some(4));
+        let area = Rect::new(0, 0, 19, 2);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(data)
+            .diff_against(b"Hel")
+            .diff_style(DIFF)
+            .highlight_style(Style::new().fg(Color::Green));
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+
+        assert_eq!(buffer.get(15, 0).style().fg, color('l').fg);
+        assert_eq!(buffer.get(16, 0).style().fg, Some(Color::Red));
+        assert_eq!(buffer.get(13, 1).style().fg, Some(Color::Green));
+    }
+
+    #[test]
+    fn modified() {
+        const MODIFIED: Style = Style::new().fg(Color::Red);
+
+        let data: Vec<u8> = (0..=0x12).collect();
+        let modified = BTreeSet::from([1, 5]);
+        let mut state = BinaryDataWidgetState::new();
+        state.select_address(Some(5));
+        let area = Rect::new(0, 0, 19, 2);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(&data)
+            .modified(&modified)
+            .modified_style(MODIFIED)
+            .highlight_style(Style::new().fg(Color::Green));
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+
+        let styles = (14..18)
+            .map(|x| buffer.get(x, 0).style().fg)
+            .chain((14..18).map(|x| buffer.get(x, 1).style().fg))
+            .collect::<Vec<_>>();
+        let expected = [
+            color('\x00').fg,
+            Some(Color::Red),
+            color('\x02').fg,
+            color('\x03').fg,
+            color('\x04').fg,
+            Some(Color::Green),
+            color('\x06').fg,
+            color('\x07').fg,
+        ];
+        assert_eq!(styles, expected);
+    }
+
+    #[test]
+    fn regions() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let regions = [
+            Region::new(0..4, Style::new().fg(Color::Red)).label("magic"),
+            Region::new(4..8, Style::new().fg(Color::Blue)).label("length"),
+            Region::new(3..5, Style::new().fg(Color::Magenta)),
+        ];
+        let mut state = BinaryDataWidgetState::new();
+        state.select_address(Some(6));
+        let area = Rect::new(0, 0, 19, 3);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(&data)
+            .regions(&regions)
+            .highlight_style(Style::new().fg(Color::Green));
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+
+        let styles = (0..3)
+            .flat_map(|y| (14..18).map(move |x| (x, y)))
+            .map(|(x, y)| buffer.get(x, y).style().fg)
+            .collect::<Vec<_>>();
+        let expected = [
+            Some(Color::Red),
+            Some(Color::Red),
+            Some(Color::Red),
+            Some(Color::Magenta),
+            Some(Color::Magenta),
+            Some(Color::Blue),
+            Some(Color::Green),
+            Some(Color::Blue),
+            color('\x08').fg,
+            color('\x09').fg,
+            color('\x0a').fg,
+            color('\x0b').fg,
+        ];
+        assert_eq!(styles, expected);
+    }
+
+    #[test]
+    fn render_to_string() {
+        let data = b"Hello world!\n";
+        let state = BinaryDataWidgetState::new();
+        let widget = BinaryDataWidget::new(data).block(Block::bordered());
+        let text = widget.render_to_string(Rect::new(0, 0, 21, 10), &state);
+        assert_eq!(
+            text,
+            "0: 4865 6c6c Hell\n4: 6f20 776f o wo\n8: 726c 6421 rld!\nc:  a        ·"
+        );
+    }
+
+    #[test]
+    fn lines_needed_matches_widget() {
+        let data = (0..=0xff).collect::<Vec<u8>>();
+        let widget = BinaryDataWidget::new(&data);
+        for width in [0, 8, 9, 19, 30, 50, 80, 200] {
+            let area = Rect::new(0, 0, width, 10);
+            assert_eq!(
+                lines_needed(data.len(), width),
+                widget.get_max_lines_of_data_in_area(area),
+                "width {width}"
+            );
+        }
+    }
+
+    #[test]
+    fn lines_needed_without_data() {
+        assert_eq!(lines_needed(0, 80), 0);
+    }
+
+    #[test]
+    fn can_render() {
+        let data = (0..=0xff).collect::<Vec<u8>>();
+        let widget = BinaryDataWidget::new(&data);
+        assert!(!widget.can_render(Rect::new(0, 0, 5, 10)));
+        assert!(widget.can_render(Rect::new(0, 0, 40, 10)));
+        assert!(!widget.can_render(Rect::new(0, 0, 40, 0)));
+    }
+
+    #[test]
+    fn can_render_within_block() {
+        let data = (0..=0xff).collect::<Vec<u8>>();
+        let widget = BinaryDataWidget::new(&data)
+            .block(Block::bordered())
+            .show_header(true);
+        assert!(!widget.can_render(Rect::new(0, 0, 40, 3)));
+        assert!(widget.can_render(Rect::new(0, 0, 40, 4)));
+    }
+
+    #[test]
+    fn can_render_without_data() {
+        let widget = BinaryDataWidget::new(&[]);
+        assert!(!widget.can_render(Rect::new(0, 0, 40, 10)));
+    }
+
+    #[test]
+    fn render_partially_outside_of_buffer() {
+        let data = (0..=0x12).collect::<Vec<u8>>();
+        let mut state = BinaryDataWidgetState::new();
+        let mut buffer = Buffer::with_lines(["xxxxxxxxxxxx", "xxxxxxxxxxxx", "xxxxxxxxxxxx"]);
+        let widget = BinaryDataWidget::new(&data).block(Block::bordered());
+        StatefulWidget::render(widget, Rect::new(2, 1, 21, 7), &mut buffer, &mut state);
+        let expected = Buffer::with_lines(["xxxxxxxxxxxx", "xx┌─────────", "xx│ 0:  0 1 "]);
+        buffer.set_style(buffer.area, Style::reset());
+        assert_eq!(buffer, expected);
+        assert_eq!(state.clicked_address(10, 2), Some(1));
+    }
+
+    #[test]
+    fn render_completely_outside_of_buffer() {
+        let data = (0..=0x12).collect::<Vec<u8>>();
+        let mut state = BinaryDataWidgetState::new();
+        let mut buffer = Buffer::with_lines(["xxxx", "xxxx"]);
+        let widget = BinaryDataWidget::new(&data);
+        StatefulWidget::render(widget, Rect::new(10, 10, 19, 5), &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(["xxxx", "xxxx"]));
+    }
+
+    #[test]
+    fn from_reader_stops_at_limit() {
+        let reader = std::io::Cursor::new(b"Hello World".to_vec());
+        let widget = BinaryDataWidget::from_reader(reader, 5).unwrap();
+        assert_eq!(widget.data.as_ref(), b"Hello");
+    }
+
+    #[test]
+    fn from_reader_shorter_than_limit() {
+        let reader = std::io::Cursor::new(b"Hi".to_vec());
+        let widget = BinaryDataWidget::from_reader(reader, 100).unwrap();
+        let state = BinaryDataWidgetState::new();
+        let expected = Buffer::with_lines(["0: 4869      Hi    "]);
+        render_widget(19, 1, widget, state, &expected);
     }
 }
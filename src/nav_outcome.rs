@@ -0,0 +1,37 @@
+/// What happened on a navigation of the [`BinaryDataWidgetState`](crate::BinaryDataWidgetState).
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NavOutcome {
+    /// The selection moved to another address
+    Moved,
+    /// The view scrolled
+    Scrolled,
+    /// Nothing changed as the start of the data is already reached
+    StartReached,
+    /// Nothing changed as the end of the data is already reached
+    EndReached,
+}
+
+impl NavOutcome {
+    /// Returns `true` when the selection or the view changed.
+    #[must_use]
+    pub const fn changed(self) -> bool {
+        matches!(self, Self::Moved | Self::Scrolled)
+    }
+
+    pub(crate) const fn moved_or(changed: bool, boundary: Self) -> Self {
+        if changed {
+            Self::Moved
+        } else {
+            boundary
+        }
+    }
+
+    pub(crate) const fn scrolled_or(changed: bool, boundary: Self) -> Self {
+        if changed {
+            Self::Scrolled
+        } else {
+            boundary
+        }
+    }
+}
@@ -0,0 +1,10 @@
+/// Half of the selected byte, see [`BinaryDataWidgetState::selected_nibble`](crate::BinaryDataWidgetState::selected_nibble).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Nibble {
+    /// The upper 4 bits like the `a` of `ab`
+    #[default]
+    High,
+    /// The lower 4 bits like the `b` of `ab`
+    Low,
+}
@@ -0,0 +1,34 @@
+use std::ops::Range;
+
+use ratatui::style::Style;
+
+/// Annotated span of addresses rendered with its own style.
+///
+/// See [`BinaryDataWidget::regions`](crate::BinaryDataWidget::regions).
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    /// Addresses of the region
+    pub range: Range<usize>,
+    /// Style used to render the bytes of the region
+    pub style: Style,
+    /// Description like `magic` or `length` for the application to show
+    pub label: Option<String>,
+}
+
+impl Region {
+    /// Create a new region without a label.
+    pub const fn new(range: Range<usize>, style: Style) -> Self {
+        Self {
+            range,
+            style,
+            label: None,
+        }
+    }
+
+    /// Describe the region with a label.
+    pub fn label<L: Into<String>>(mut self, label: L) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
@@ -1,80 +1,299 @@
 use ratatui::layout::Rect;
 
+use crate::{AddressFormat, AddressOrigin, AddressPosition, ByteFormat, ByteOrder, HexLayout};
+
+/// Options of the widget which influence the [`RenderPositions`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LayoutOptions {
+    /// Fixed amount of bytes per row instead of the automatic calculation
+    pub bytes_per_row: Option<u16>,
+    /// Number system of the address column
+    pub address_format: AddressFormat,
+    pub address_origin: AddressOrigin,
+    pub address_position: AddressPosition,
+    /// Added to the addresses for display only
+    pub base_address: u64,
+    pub byte_format: ByteFormat,
+    /// Order of the bytes within each group of the hex column
+    pub byte_order: ByteOrder,
+    /// Spacing of the columns and the grouping of the hex column
+    pub hex_layout: HexLayout,
+    pub show_hex_column: bool,
+    pub show_char_column: bool,
+    /// Reserve a column between the addresses and the data for a marker of each row
+    pub show_row_marker: bool,
+    /// Amount of blank rows between the rows of data
+    pub row_spacing: u16,
+    /// Show each group of this many bytes as a single hex number
+    pub word_size: Option<u8>,
+}
+
+impl LayoutOptions {
+    pub const fn new() -> Self {
+        Self {
+            bytes_per_row: None,
+            address_format: AddressFormat::Hex,
+            address_origin: AddressOrigin::Absolute,
+            address_position: AddressPosition::Left,
+            base_address: 0,
+            byte_format: ByteFormat::Hex,
+            byte_order: ByteOrder::Native,
+            hex_layout: HexLayout::new(),
+            show_hex_column: true,
+            show_char_column: true,
+            show_row_marker: false,
+            row_spacing: 0,
+            word_size: None,
+        }
+    }
+
+    /// Options with the grouping and format the word view needs.
+    const fn effective(self) -> Self {
+        let Some(word_size) = self.word_size else {
+            return self;
+        };
+        Self {
+            byte_format: ByteFormat::Hex,
+            hex_layout: HexLayout {
+                group_size: word_size as u16,
+                ..self.hex_layout
+            },
+            ..self
+        }
+    }
+}
+
 #[must_use]
-#[derive(Debug, Clone, Copy)]
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct RenderPositions {
     pub inner_area: Rect,
     pub biggest_address: usize,
     pub address_width: u16,
+    /// Amount of addresses per row of data
     pub per_row: u16,
+    /// Amount of addresses per row which fit into the area and are therefore rendered
+    pub visible_per_row: u16,
     pub available_data_lines: usize,
+    /// Show the address column in front of the data
+    pub show_left_address: bool,
+    /// Column of the address column after the data when it is shown
+    pub offset_x_right_address: Option<u16>,
+    /// Column of the row marker, only relevant when it is shown
+    pub offset_x_marker: u16,
     pub offset_x_hex: u16,
     pub offset_x_char: u16,
+    /// Amount of columns a single byte takes in the hex column
+    pub byte_width: u16,
+    pub group_size: u16,
+    /// Amount of columns between the groups of the hex column
+    pub group_gap: u16,
+    /// Order of the bytes within each group of the hex column
+    pub byte_order: ByteOrder,
+    pub show_hex_column: bool,
+    pub show_char_column: bool,
+    /// Amount of blank rows between the rows of data
+    pub row_spacing: u16,
+    /// Each group in the hex column is shown as a single number
+    pub word_view: bool,
 }
 
 impl RenderPositions {
     #[must_use]
-    pub fn new(inner_area: Rect, data_length: usize) -> Option<Self> {
-        const TWO_ADDRESSES_TAKE: u16 = 4 + 2 + 1; // binary + char + whitespace
-        const CHAR_OFFSET_PER_TWO: u16 = 4 + 1;
+    pub fn new(inner_area: Rect, data_length: usize, options: LayoutOptions) -> Option<Self> {
+        const MIN_AUTO_PER_ROW: u16 = 4;
 
-        if inner_area.width < 9 || inner_area.height < 1 || data_length == 0 {
+        let options = options.effective();
+        if inner_area.width < 9
+            || inner_area.height < 1
+            || data_length == 0
+            || !(options.show_hex_column || options.show_char_column)
+        {
             return None;
         }
 
         let biggest_address = data_length.saturating_sub(1);
-        #[allow(
-            clippy::cast_possible_truncation,
-            clippy::cast_precision_loss,
-            clippy::cast_sign_loss
-        )]
-        let address_width = (biggest_address as f32).log(16.0).ceil() as u16;
+        let biggest_displayed_address = options.base_address.saturating_add(biggest_address as u64);
+        let radix = options.address_format.radix();
+        let mut address_width = digits_of(biggest_displayed_address, radix);
+        if options.address_origin == AddressOrigin::RelativeToSelection {
+            // The distance to the selection is at most the biggest address plus its sign
+            let relative_width = digits_of(biggest_address as u64, radix).saturating_add(1);
+            address_width = address_width.max(relative_width);
+        }
+        let marker_width = u16::from(options.show_row_marker);
+        let show_left_address = options.address_position.left();
+        let left_address_width = if show_left_address {
+            address_width.saturating_add(options.hex_layout.address_suffix_width())
+        } else {
+            0
+        };
+        // A gap in front and the column of the scrollbar after the right addresses
+        let right_address_width = if options.address_position.right() {
+            address_width.saturating_add(2)
+        } else {
+            0
+        };
         let data_width = inner_area
             .width
-            .saturating_sub(2)
-            .saturating_sub(address_width);
-
-        let pairs_per_row_max = data_width.saturating_div(TWO_ADDRESSES_TAKE);
-        if pairs_per_row_max < 2 {
-            return None;
-        }
+            .saturating_sub(left_address_width)
+            .saturating_sub(right_address_width)
+            .saturating_sub(marker_width);
 
-        let pairs_per_row = {
-            let mut pairs_per_row: u16 = 1;
+        let (per_row, visible_per_row) = if let Some(per_row) = options.bytes_per_row {
+            let mut visible_per_row = per_row;
+            while visible_per_row > 0 && Self::data_width_of(visible_per_row, options) > data_width
+            {
+                visible_per_row -= 1;
+            }
+            (per_row, visible_per_row)
+        } else {
+            let mut per_row = MIN_AUTO_PER_ROW;
             loop {
-                let next = pairs_per_row.saturating_mul(2);
-                if next > pairs_per_row_max {
+                let next = per_row.saturating_mul(2);
+                if Self::data_width_of(next, options) > data_width {
                     break;
                 }
-                pairs_per_row = next;
+                per_row = next;
+            }
+            if Self::data_width_of(per_row, options) > data_width {
+                return None;
             }
-            pairs_per_row
+            (per_row, per_row)
         };
-        let per_row = pairs_per_row.saturating_mul(2);
+        if per_row == 0 || visible_per_row == 0 {
+            return None;
+        }
 
         let available_data_lines = data_length.div_ceil(per_row as usize);
 
-        let offset_x_hex = inner_area.x.saturating_add(address_width).saturating_add(2);
+        let offset_x_marker = inner_area.x.saturating_add(left_address_width);
+        let offset_x_hex = offset_x_marker.saturating_add(marker_width);
         let offset_x_char =
-            offset_x_hex.saturating_add(pairs_per_row.saturating_mul(CHAR_OFFSET_PER_TWO));
+            offset_x_hex.saturating_add(Self::hex_width_of(visible_per_row, options));
+        let offset_x_right_address = options.address_position.right().then(|| {
+            offset_x_hex
+                .saturating_add(Self::data_width_of(visible_per_row, options))
+                .saturating_add(1)
+        });
 
         Some(Self {
             inner_area,
             biggest_address,
             address_width,
             per_row,
+            visible_per_row,
             available_data_lines,
+            show_left_address,
+            offset_x_right_address,
+            offset_x_marker,
             offset_x_hex,
             offset_x_char,
+            byte_width: options.byte_format.width(),
+            group_size: options.hex_layout.group_size,
+            group_gap: options.hex_layout.group_gap,
+            byte_order: options.byte_order,
+            show_hex_column: options.show_hex_column,
+            show_char_column: options.show_char_column,
+            row_spacing: options.row_spacing,
+            word_view: options.word_size.is_some(),
         })
     }
 
+    /// Width of the hex column including the whitespace in front of the char column
+    const fn hex_width_of(addresses: u16, options: LayoutOptions) -> u16 {
+        if !options.show_hex_column {
+            return 0;
+        }
+        let layout = options.hex_layout;
+        let gaps = addresses.div_ceil(layout.group_size).saturating_sub(1);
+        addresses
+            .saturating_mul(options.byte_format.width())
+            .saturating_add(gaps.saturating_mul(layout.group_gap))
+            .saturating_add(layout.char_gap)
+    }
+
+    /// Width of the hex and char column for the given amount of addresses
+    const fn data_width_of(addresses: u16, options: LayoutOptions) -> u16 {
+        let hex_width = Self::hex_width_of(addresses, options);
+        if options.show_char_column {
+            hex_width.saturating_add(addresses)
+        } else {
+            hex_width
+        }
+    }
+
+    /// Column of the byte with the given index on the row when the first `offset_column` bytes of each row are scrolled out of view.
+    ///
+    /// The groups start at the columns of the whole row so a gap is only in front of the first byte of a group.
     #[must_use]
-    pub const fn x_hex(&self, index_on_row: u16) -> u16 {
-        let pair_index = index_on_row.saturating_div(2);
+    pub const fn x_hex(&self, offset_column: u16, index_on_row: u16) -> u16 {
+        let column = offset_column.saturating_add(index_on_row);
+        let gaps = column / self.group_size - offset_column / self.group_size;
         self.offset_x_hex
-            .saturating_add(index_on_row.saturating_mul(2))
-            .saturating_add(pair_index)
+            .saturating_add(index_on_row.saturating_mul(self.byte_width))
+            .saturating_add(gaps.saturating_mul(self.group_gap))
+    }
+
+    /// Amount of bytes per row which are shown when the first `offset_column` bytes of each row are scrolled out of view.
+    ///
+    /// A group cut off at the start of the view needs an additional gap which might cost the last byte.
+    #[must_use]
+    pub const fn visible_columns(&self, offset_column: u16) -> u16 {
+        let remaining = self.per_row.saturating_sub(offset_column);
+        let mut columns = if remaining < self.visible_per_row {
+            remaining
+        } else {
+            self.visible_per_row
+        };
+        if self.show_hex_column {
+            let last_x = self.x_hex(0, self.visible_per_row.saturating_sub(1));
+            while columns > 0 && self.x_hex(offset_column, columns - 1) > last_x {
+                columns -= 1;
+            }
+        }
+        columns
+    }
+
+    /// Biggest amount of bytes per row which can be scrolled out of view while the end of the row stays reachable.
+    #[must_use]
+    pub const fn max_offset_column(&self) -> u16 {
+        let mut offset_column = self.per_row.saturating_sub(self.visible_per_row);
+        while offset_column < self.per_row
+            && offset_column.saturating_add(self.visible_columns(offset_column)) < self.per_row
+        {
+            offset_column += 1;
+        }
+        offset_column
+    }
+
+    /// Index on the row where the byte with the given index is shown in the hex column.
+    ///
+    /// The groups start at the columns of the whole row, see [`x_hex`](Self::x_hex).
+    /// A group cut off at either edge of the view is reversed within its visible part.
+    /// `visible_columns` is the result of [`visible_columns`](Self::visible_columns) for the same `offset_column`.
+    /// This is its own inverse so it also maps a position in the hex column back to the index of its byte.
+    #[must_use]
+    pub const fn hex_slot(
+        &self,
+        offset_column: u16,
+        visible_columns: u16,
+        index_on_row: u16,
+    ) -> u16 {
+        match self.byte_order {
+            ByteOrder::Native => index_on_row,
+            ByteOrder::ReversedGroups => {
+                let in_group = offset_column.saturating_add(index_on_row) % self.group_size;
+                let group_start = index_on_row.saturating_sub(in_group);
+                let group_end = index_on_row.saturating_add(self.group_size - in_group);
+                let group_end = if group_end > visible_columns {
+                    visible_columns
+                } else {
+                    group_end
+                };
+                (group_start + group_end).saturating_sub(1 + index_on_row)
+            }
+        }
     }
 
     #[must_use]
@@ -82,23 +301,457 @@ impl RenderPositions {
         self.offset_x_char.saturating_add(index_on_row)
     }
 
+    /// Amount of rows a line of data takes including the spacing after it
+    #[must_use]
+    pub const fn row_height(&self) -> u16 {
+        self.row_spacing.saturating_add(1)
+    }
+
+    /// Amount of lines of data fitting into the given height.
+    ///
+    /// The last line does not need the spacing after it.
+    #[must_use]
+    pub const fn lines_in_height(&self, height: u16) -> u16 {
+        height.saturating_add(self.row_spacing) / self.row_height()
+    }
+
+    /// Amount of rows needed to show all the lines of data
+    #[must_use]
+    pub const fn height_of_all_lines(&self) -> usize {
+        self.available_data_lines
+            .saturating_mul(self.row_height() as usize)
+            .saturating_sub(self.row_spacing as usize)
+    }
+
+    /// Address on the given display position when the first `offset_column` bytes of each row are scrolled out of view.
+    ///
+    /// Positions in the address column map to the first visible address of the row.
+    /// The whitespace after a group of the hex column maps to the last byte of the group.
+    /// Positions right of the data map to the last address of the row.
+    /// Returns `None` when the position is after the end of the data or on the address column on the right.
     #[must_use]
-    pub fn address_at(&self, offset_address: usize, column: u16, row: u16) -> usize {
-        let row_offset = row.saturating_sub(self.inner_area.top());
+    pub fn address_at(
+        &self,
+        offset_address: usize,
+        offset_column: u16,
+        column: u16,
+        row: u16,
+    ) -> Option<usize> {
+        if self
+            .offset_x_right_address
+            .is_some_and(|right_address| column >= right_address)
+        {
+            return None;
+        }
+        let row_offset = row.saturating_sub(self.inner_area.top()) / self.row_height();
         let offset_address = offset_address
-            .saturating_add((row_offset as usize).saturating_mul(self.per_row as usize));
-        if column <= self.offset_x_hex {
-            offset_address
-        } else if column < self.offset_x_char.saturating_sub(1) {
-            let diff = column.saturating_sub(self.offset_x_hex);
-            let index = diff
-                .saturating_sub(diff.saturating_div(5))
-                .saturating_div(2);
-            offset_address.saturating_add(index as usize)
+            .saturating_add((row_offset as usize).saturating_mul(self.per_row as usize))
+            .saturating_add(offset_column as usize);
+        let visible_columns = self.visible_columns(offset_column);
+        let in_hex_column =
+            self.show_hex_column && (!self.show_char_column || column < self.offset_x_char);
+        let index = if in_hex_column {
+            // Each group is followed by the gap which belongs to the last byte of the group
+            let bytes_width = self.group_size.saturating_mul(self.byte_width);
+            let group_width = bytes_width.saturating_add(self.group_gap);
+            // Position within the whole row so the groups start at the same columns as rendered
+            let diff = column
+                .saturating_sub(self.offset_x_hex)
+                .saturating_add(self.x_hex(0, offset_column) - self.offset_x_hex);
+            let group_index = diff / group_width;
+            let in_group = (diff % group_width).min(bytes_width.saturating_sub(1));
+            let slot = group_index
+                .saturating_mul(self.group_size)
+                .saturating_add(in_group / self.byte_width)
+                .saturating_sub(offset_column)
+                .min(visible_columns.saturating_sub(1));
+            if self.word_view {
+                // Words are selected by their first byte
+                let in_word = offset_column.saturating_add(slot) % self.group_size;
+                slot.saturating_sub(in_word)
+            } else {
+                self.hex_slot(offset_column, visible_columns, slot)
+            }
         } else {
-            let diff = column.saturating_sub(self.offset_x_char);
-            let index = diff.min(self.per_row.saturating_sub(1));
-            offset_address.saturating_add(index as usize)
+            column
+                .saturating_sub(self.offset_x_char)
+                .min(visible_columns.saturating_sub(1))
+        };
+        let address = offset_address.saturating_add(index as usize);
+        (address <= self.biggest_address).then_some(address)
+    }
+}
+
+/// Returns the amount of lines needed to show `data_len` bytes in an area of `inner_width` columns.
+///
+/// This uses the same calculation as [`BinaryDataWidget::get_max_lines_of_data_in_area`](crate::BinaryDataWidget::get_max_lines_of_data_in_area)
+/// with the default layout of the widget and without a block or header.
+/// It allows to decide on a height before building the widget.
+/// Returns `0` when the width is too small to show any data.
+#[must_use]
+pub fn lines_needed(data_len: usize, inner_width: u16) -> usize {
+    let area = Rect::new(0, 0, inner_width, 1);
+    RenderPositions::new(area, data_len, LayoutOptions::new())
+        .map_or(0, |positions| positions.available_data_lines)
+}
+
+/// Amount of digits needed to display `value` in the given `radix`. At least 1.
+fn digits_of(mut value: u64, radix: u16) -> u16 {
+    let radix = u64::from(radix);
+    let mut digits = 1;
+    while value >= radix {
+        value /= radix;
+        digits += 1;
+    }
+    digits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn options(bytes_per_row: Option<u16>) -> LayoutOptions {
+        LayoutOptions {
+            bytes_per_row,
+            ..LayoutOptions::new()
+        }
+    }
+
+    #[test]
+    fn auto_per_row_is_power_of_two() {
+        let positions =
+            RenderPositions::new(Rect::new(0, 0, 30, 10), 0x100, LayoutOptions::new()).unwrap();
+        assert_eq!(positions.per_row, 4);
+        let positions =
+            RenderPositions::new(Rect::new(0, 0, 50, 10), 0x100, LayoutOptions::new()).unwrap();
+        assert_eq!(positions.per_row, 8);
+        assert_eq!(positions.visible_per_row, 8);
+    }
+
+    #[test]
+    fn fixed_per_row_fits() {
+        let positions =
+            RenderPositions::new(Rect::new(0, 0, 60, 10), 0x100, options(Some(6))).unwrap();
+        assert_eq!(positions.per_row, 6);
+        assert_eq!(positions.visible_per_row, 6);
+        assert_eq!(positions.available_data_lines, 43);
+    }
+
+    #[test]
+    fn fixed_per_row_truncated() {
+        let positions =
+            RenderPositions::new(Rect::new(0, 0, 19, 10), 0x100, options(Some(16))).unwrap();
+        assert_eq!(positions.per_row, 16);
+        assert_eq!(positions.visible_per_row, 4);
+        assert_eq!(positions.available_data_lines, 16);
+    }
+
+    #[test]
+    fn unaligned_offset_column() {
+        let positions =
+            RenderPositions::new(Rect::new(0, 0, 19, 10), 0x100, options(Some(16))).unwrap();
+        assert_eq!(positions.visible_columns(0), 4);
+        assert_eq!(positions.visible_columns(1), 3);
+        assert_eq!(positions.visible_columns(2), 4);
+        assert_eq!(positions.max_offset_column(), 12);
+        assert_eq!(positions.x_hex(1, 0), 4);
+        assert_eq!(positions.x_hex(1, 1), 7);
+        assert_eq!(positions.x_hex(1, 2), 9);
+        assert_eq!(positions.address_at(0, 1, 5, 0), Some(1));
+        // The whitespace after the cut off group
+        assert_eq!(positions.address_at(0, 1, 6, 0), Some(1));
+        assert_eq!(positions.address_at(0, 1, 7, 0), Some(2));
+        assert_eq!(positions.address_at(0, 1, 10, 0), Some(3));
+        assert_eq!(positions.address_at(16, 1, 14, 0), Some(17));
+        assert_eq!(positions.address_at(16, 1, 17, 0), Some(19));
+    }
+
+    #[test]
+    fn click_without_char_column() {
+        let options = LayoutOptions {
+            show_char_column: false,
+            ..LayoutOptions::new()
+        };
+        let positions = RenderPositions::new(Rect::new(0, 0, 24, 10), 0x13, options).unwrap();
+        assert_eq!(positions.per_row, 8);
+        assert_eq!(positions.address_at(0, 0, 21, 0), Some(7));
+        assert_eq!(positions.address_at(0, 0, 23, 1), Some(15));
+    }
+
+    #[test]
+    fn only_char_column() {
+        let options = LayoutOptions {
+            show_hex_column: false,
+            ..LayoutOptions::new()
+        };
+        let positions = RenderPositions::new(Rect::new(0, 0, 24, 10), 0x13, options).unwrap();
+        assert_eq!(positions.per_row, 16);
+        assert_eq!(positions.offset_x_char, 4);
+        assert_eq!(positions.x_char(3), 7);
+        assert_eq!(positions.address_at(0, 0, 2, 0), Some(0));
+        assert_eq!(positions.address_at(0, 0, 6, 1), Some(18));
+        assert_eq!(positions.address_at(0, 0, 7, 1), None);
+    }
+
+    #[test]
+    fn no_column_at_all() {
+        let options = LayoutOptions {
+            show_hex_column: false,
+            show_char_column: false,
+            ..LayoutOptions::new()
+        };
+        assert!(RenderPositions::new(Rect::new(0, 0, 24, 10), 0x13, options).is_none());
+    }
+
+    #[test]
+    fn group_size() {
+        let options = LayoutOptions {
+            hex_layout: HexLayout {
+                group_size: 4,
+                ..HexLayout::new()
+            },
+            ..LayoutOptions::new()
+        };
+        let positions = RenderPositions::new(Rect::new(0, 0, 30, 10), 0x13, options).unwrap();
+        assert_eq!(positions.per_row, 8);
+        assert_eq!(positions.x_hex(0, 3), 10);
+        assert_eq!(positions.x_hex(0, 4), 13);
+        assert_eq!(positions.offset_x_char, 22);
+        assert_eq!(positions.address_at(0, 0, 10, 0), Some(3));
+        assert_eq!(positions.address_at(0, 0, 11, 0), Some(3));
+        assert_eq!(positions.address_at(0, 0, 13, 0), Some(4));
+        assert_eq!(positions.address_at(0, 0, 20, 0), Some(7));
+    }
+
+    #[test]
+    fn wide_gaps() {
+        let options = LayoutOptions {
+            hex_layout: HexLayout {
+                address_suffix: " | ",
+                group_gap: 2,
+                char_gap: 3,
+                group_size: 2,
+            },
+            ..LayoutOptions::new()
+        };
+        let positions = RenderPositions::new(Rect::new(0, 0, 30, 10), 0x13, options).unwrap();
+        assert_eq!(positions.per_row, 4);
+        assert_eq!(positions.offset_x_hex, 5);
+        assert_eq!(positions.x_hex(0, 1), 7);
+        assert_eq!(positions.x_hex(0, 2), 11);
+        assert_eq!(positions.offset_x_char, 18);
+        assert_eq!(positions.address_at(0, 0, 9, 0), Some(1));
+        assert_eq!(positions.address_at(0, 0, 10, 0), Some(1));
+        assert_eq!(positions.address_at(0, 0, 11, 0), Some(2));
+        assert_eq!(positions.address_at(0, 0, 16, 0), Some(3));
+    }
+
+    #[test]
+    fn row_spacing() {
+        let options = LayoutOptions {
+            row_spacing: 1,
+            ..LayoutOptions::new()
+        };
+        let positions = RenderPositions::new(Rect::new(0, 2, 19, 10), 0x13, options).unwrap();
+        assert_eq!(positions.available_data_lines, 5);
+        assert_eq!(positions.height_of_all_lines(), 9);
+        assert_eq!(positions.lines_in_height(4), 2);
+        assert_eq!(positions.lines_in_height(5), 3);
+        assert_eq!(positions.address_at(0, 0, 4, 2), Some(0));
+        assert_eq!(positions.address_at(0, 0, 4, 3), Some(0));
+        assert_eq!(positions.address_at(0, 0, 4, 4), Some(4));
+        assert_eq!(positions.address_at(0, 0, 4, 6), Some(8));
+    }
+
+    #[test]
+    fn address_position_right() {
+        let options = LayoutOptions {
+            address_position: AddressPosition::Right,
+            ..LayoutOptions::new()
+        };
+        let positions = RenderPositions::new(Rect::new(0, 0, 19, 10), 0x13, options).unwrap();
+        assert_eq!(positions.per_row, 4);
+        assert_eq!(positions.offset_x_hex, 0);
+        assert_eq!(positions.offset_x_char, 10);
+        assert_eq!(positions.offset_x_right_address, Some(15));
+        assert_eq!(positions.address_at(0, 0, 14, 0), Some(3));
+        assert_eq!(positions.address_at(0, 0, 15, 0), None);
+        assert_eq!(positions.address_at(0, 0, 18, 0), None);
+    }
+
+    #[test]
+    fn address_position_both() {
+        let options = LayoutOptions {
+            address_position: AddressPosition::Both,
+            ..LayoutOptions::new()
+        };
+        assert!(RenderPositions::new(Rect::new(0, 0, 19, 10), 0x13, options).is_none());
+        let positions = RenderPositions::new(Rect::new(0, 0, 23, 10), 0x13, options).unwrap();
+        assert_eq!(positions.offset_x_hex, 4);
+        assert_eq!(positions.offset_x_right_address, Some(19));
+    }
+
+    #[test]
+    fn word_view() {
+        let options = LayoutOptions {
+            byte_format: ByteFormat::Binary,
+            word_size: Some(4),
+            ..LayoutOptions::new()
+        };
+        let positions = RenderPositions::new(Rect::new(0, 0, 40, 10), 0x13, options).unwrap();
+        assert_eq!(positions.per_row, 8);
+        assert_eq!(positions.byte_width, 2);
+        assert_eq!(positions.group_size, 4);
+        assert_eq!(positions.x_hex(0, 4), 13);
+        assert_eq!(positions.address_at(0, 0, 4, 0), Some(0));
+        assert_eq!(positions.address_at(0, 0, 10, 0), Some(0));
+        assert_eq!(positions.address_at(0, 0, 13, 0), Some(4));
+        assert_eq!(positions.address_at(0, 0, 22, 0), Some(0));
+    }
+
+    #[test]
+    fn binary_byte_format() {
+        let options = LayoutOptions {
+            byte_format: ByteFormat::Binary,
+            ..LayoutOptions::new()
+        };
+        let positions = RenderPositions::new(Rect::new(0, 0, 50, 10), 0x13, options).unwrap();
+        assert_eq!(positions.per_row, 4);
+        assert_eq!(positions.x_hex(0, 1), 12);
+        assert_eq!(positions.x_hex(0, 2), 21);
+        assert_eq!(positions.offset_x_char, 38);
+        assert_eq!(positions.address_at(0, 0, 19, 0), Some(1));
+        assert_eq!(positions.address_at(0, 0, 21, 0), Some(2));
+    }
+
+    #[test]
+    fn base_address_widens_address_column() {
+        let options = LayoutOptions {
+            base_address: 0x4000_0000,
+            ..LayoutOptions::new()
+        };
+        let positions = RenderPositions::new(Rect::new(0, 0, 50, 10), 0x13, options).unwrap();
+        assert_eq!(positions.biggest_address, 0x12);
+        assert_eq!(positions.address_width, 8);
+    }
+
+    #[test]
+    fn address_width_boundaries() {
+        let area = Rect::new(0, 0, 50, 10);
+        for (data_length, expected) in [(1, 1), (16, 1), (17, 2), (256, 2), (257, 3)] {
+            let positions = RenderPositions::new(area, data_length, LayoutOptions::new()).unwrap();
+            assert_eq!(
+                positions.address_width, expected,
+                "data length {data_length}"
+            );
+        }
+    }
+
+    #[test]
+    fn digits_of_extremes() {
+        assert_eq!(digits_of(0, 16), 1);
+        assert_eq!(digits_of(0xf, 16), 1);
+        assert_eq!(digits_of(0x10, 16), 2);
+        assert_eq!(digits_of(999, 10), 3);
+        assert_eq!(digits_of(1000, 10), 4);
+        assert_eq!(digits_of(0o777, 8), 3);
+        assert_eq!(digits_of(0o1000, 8), 4);
+        assert_eq!(digits_of(u64::MAX, 16), 16);
+        assert_eq!(digits_of(u64::MAX, 10), 20);
+        assert_eq!(digits_of(u64::MAX, 8), 22);
+    }
+
+    #[test]
+    fn base_address_saturates() {
+        let options = LayoutOptions {
+            base_address: u64::MAX,
+            ..LayoutOptions::new()
+        };
+        let positions = RenderPositions::new(Rect::new(0, 0, 50, 10), 0x13, options).unwrap();
+        assert_eq!(positions.address_width, 16);
+    }
+
+    #[test]
+    fn reversed_groups() {
+        let options = LayoutOptions {
+            byte_order: ByteOrder::ReversedGroups,
+            hex_layout: HexLayout {
+                group_size: 4,
+                ..HexLayout::new()
+            },
+            ..LayoutOptions::new()
+        };
+        let positions = RenderPositions::new(Rect::new(0, 0, 30, 10), 0x13, options).unwrap();
+        assert_eq!(positions.per_row, 8);
+        assert_eq!(positions.hex_slot(0, 8, 0), 3);
+        assert_eq!(positions.hex_slot(0, 8, 3), 0);
+        assert_eq!(positions.hex_slot(0, 8, 5), 6);
+        assert_eq!(positions.address_at(0, 0, 4, 0), Some(3));
+        assert_eq!(positions.address_at(0, 0, 10, 0), Some(0));
+        assert_eq!(positions.address_at(0, 0, 11, 0), Some(0));
+        assert_eq!(positions.address_at(0, 0, 13, 0), Some(7));
+        assert_eq!(positions.address_at(0, 0, 22, 0), Some(0));
+    }
+
+    #[test]
+    fn address_at_gutter() {
+        let positions =
+            RenderPositions::new(Rect::new(0, 0, 19, 10), 0x13, LayoutOptions::new()).unwrap();
+        assert_eq!(positions.per_row, 4);
+        assert_eq!(positions.address_at(0, 0, 0, 1), Some(4));
+        assert_eq!(positions.address_at(0, 0, 1, 4), Some(16));
+        assert_eq!(positions.address_at(0, 0, 0, 5), None);
+        assert_eq!(positions.address_at(4, 0, 0, 4), None);
+        assert_eq!(positions.address_at(0, 0, 12, 4), None);
+    }
+
+    #[test]
+    fn row_marker_shifts_data() {
+        let options = LayoutOptions {
+            show_row_marker: true,
+            ..LayoutOptions::new()
+        };
+        let positions = RenderPositions::new(Rect::new(0, 0, 20, 10), 0x13, options).unwrap();
+        assert_eq!(positions.per_row, 4);
+        assert_eq!(positions.offset_x_marker, 4);
+        assert_eq!(positions.offset_x_hex, 5);
+        assert_eq!(positions.offset_x_char, 15);
+        assert_eq!(positions.address_at(0, 0, 4, 1), Some(4));
+        assert_eq!(positions.address_at(0, 0, 7, 1), Some(5));
+        assert_eq!(positions.address_at(0, 0, 16, 1), Some(5));
+    }
+
+    #[test]
+    fn relative_address_has_sign_column() {
+        let options = LayoutOptions {
+            address_origin: AddressOrigin::RelativeToSelection,
+            ..LayoutOptions::new()
+        };
+        let positions = RenderPositions::new(Rect::new(0, 0, 50, 10), 0x13, options).unwrap();
+        assert_eq!(positions.address_width, 3);
+
+        let options = LayoutOptions {
+            base_address: 0x4000_0000,
+            ..options
+        };
+        let positions = RenderPositions::new(Rect::new(0, 0, 50, 10), 0x13, options).unwrap();
+        assert_eq!(positions.address_width, 8);
+    }
+
+    #[test]
+    fn address_at_every_column() {
+        let positions =
+            RenderPositions::new(Rect::new(0, 0, 19, 10), 0x13, LayoutOptions::new()).unwrap();
+        assert_eq!(positions.offset_x_hex, 4);
+        assert_eq!(positions.offset_x_char, 14);
+        // " 0:  0 1  2 3 ···· "
+        let expected = [0, 0, 0, 0, 0, 0, 1, 1, 1, 2, 2, 3, 3, 3, 0, 1, 2, 3, 3];
+        for (column, expected) in (0..).zip(expected) {
+            assert_eq!(
+                positions.address_at(0, 0, column, 0),
+                Some(expected),
+                "column {column}"
+            );
         }
     }
 }
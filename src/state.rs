@@ -1,23 +1,143 @@
-use crate::RenderPositions;
+use std::collections::BTreeSet;
+use std::ops::{Range, RangeInclusive};
+
+use ratatui::layout::{Position, Rect};
+
+use crate::checksum::crc32;
+use crate::render_positions::LayoutOptions;
+use crate::{DataSource, NavOutcome, Nibble, RenderPositions, ViewToken};
 
 /// Keeps the state of a [`BinaryDataWidget`](crate::BinaryDataWidget).
+///
+/// With the `serde` feature the offset, selection and bookmarks can be serialized.
+/// Everything depending on the last render is skipped.
 #[must_use]
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(clippy::struct_excessive_bools)]
 pub struct State {
+    /// Sorted and deduplicated
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "deserialize_bookmarks")
+    )]
+    pub(super) bookmarks: Vec<usize>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(super) center_selected_on_next_render: bool,
+    /// Location of the slice, length and CRC32 of the data on the last checksum
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(super) checksum_cache: Option<(Option<usize>, usize, u32)>,
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub(super) ensure_selected_in_view_on_next_render: bool,
+    /// Scroll to the end when the data grows
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(super) follow: bool,
+    /// Address under the mouse
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(super) hovered_address: Option<usize>,
+    /// Amount of lines available for data on last render
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(super) last_available_height: usize,
+    /// Length of the data on last render
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(super) last_data_len: usize,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(super) last_needle: Vec<u8>,
+    /// The `last_needle` was searched ignoring the ASCII case
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(super) last_needle_ignore_case: bool,
+    /// Area, data length and layout the `last_render_positions` were calculated for
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(super) last_render_inputs: Option<(Rect, usize, LayoutOptions)>,
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub(super) last_render_positions: Option<RenderPositions>,
+    /// Amount of lines with data shown on last render
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(super) last_visible_lines: usize,
+    /// Half of the selected byte, only relevant with `nibble_navigation`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(super) nibble: Nibble,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(super) nibble_navigation: bool,
     pub(super) offset_address: usize,
+    /// Amount of byte columns skipped on the left when scrolled horizontally
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(super) offset_column: u16,
     pub(super) selected_address: Option<usize>,
+    pub(super) selection_anchor: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(super) selection_follows_scroll: bool,
+    /// Scattered addresses selected in addition to the selected range
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(super) selection_set: BTreeSet<usize>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(super) wrap_navigation: bool,
 }
 
 impl State {
     pub const fn new() -> Self {
         Self {
+            bookmarks: Vec::new(),
+            center_selected_on_next_render: false,
+            checksum_cache: None,
             ensure_selected_in_view_on_next_render: false,
+            follow: false,
+            hovered_address: None,
+            last_available_height: 0,
+            last_data_len: 0,
+            last_needle: Vec::new(),
+            last_needle_ignore_case: false,
+            last_render_inputs: None,
             last_render_positions: None,
+            last_visible_lines: 0,
+            nibble: Nibble::High,
+            nibble_navigation: false,
             offset_address: 0,
+            offset_column: 0,
             selected_address: None,
+            selection_anchor: None,
+            selection_follows_scroll: false,
+            selection_set: BTreeSet::new(),
+            wrap_navigation: false,
+        }
+    }
+
+    /// Calculate the [`RenderPositions`] for a render.
+    ///
+    /// Reuses the positions of the last render when the inputs are unchanged.
+    pub(crate) fn update_render_positions(
+        &mut self,
+        area: Rect,
+        data_length: usize,
+        options: LayoutOptions,
+    ) -> Option<RenderPositions> {
+        let inputs = (area, data_length, options);
+        if self.last_render_inputs != Some(inputs) {
+            self.last_render_positions = RenderPositions::new(area, data_length, options);
+            self.last_render_inputs = Some(inputs);
+        }
+        self.last_render_positions
+    }
+
+    /// CRC32 of the data, cached while the data keeps its length and the location of its slice.
+    pub(crate) fn checksum<D: DataSource + ?Sized>(&mut self, data: &D) -> u32 {
+        let slice = data.as_slice();
+        let key = (slice.map(|slice| slice.as_ptr().addr()), data.len());
+        if let Some((location, length, checksum)) = self.checksum_cache {
+            if (location, length) == key {
+                return checksum;
+            }
         }
+        let checksum = slice.map_or_else(|| crc32(data), crc32);
+        self.checksum_cache = Some((key.0, key.1, checksum));
+        checksum
+    }
+
+    /// Forget the cached checksum so the next render computes it again.
+    ///
+    /// Call this after changing the bytes in place or after replacing the data with other data of the same length.
+    pub const fn invalidate_checksum(&mut self) {
+        self.checksum_cache = None;
     }
 
     #[must_use]
@@ -25,16 +145,106 @@ impl State {
         self.offset_address
     }
 
+    /// Returns the first line of the view.
+    ///
+    /// Before the first render 8 bytes per row are assumed.
+    #[must_use]
+    pub fn offset_line(&self) -> usize {
+        self.offset_address / self.last_per_row()
+    }
+
+    /// Scroll the view to start at the given line.
+    ///
+    /// Before the first render 8 bytes per row are assumed.
+    pub fn set_offset_line(&mut self, line: usize) {
+        self.offset_address = line.saturating_mul(self.last_per_row());
+    }
+
+    /// Amount of byte columns skipped on the left when scrolled horizontally
+    #[must_use]
+    pub const fn get_offset_column(&self) -> u16 {
+        self.offset_column
+    }
+
     #[must_use]
     pub const fn selected_address(&self) -> Option<usize> {
         self.selected_address
     }
 
+    /// Returns the value of the selected byte in the `data`.
+    ///
+    /// A selection after the end of the `data`, for example from before the data shrunk, is clamped to the last byte.
+    #[must_use]
+    pub fn selected_value(&self, data: &[u8]) -> Option<u8> {
+        let selected = self.selected_address?;
+        data.get(selected.min(data.len().saturating_sub(1)))
+            .copied()
+    }
+
+    /// Read an `u16` in little-endian from the `data` starting at the selected address.
+    ///
+    /// Returns `None` without a selection or when there are not enough bytes after the selection.
+    #[must_use]
+    pub fn read_u16_le(&self, data: &[u8]) -> Option<u16> {
+        self.read_selected(data).map(u16::from_le_bytes)
+    }
+
+    /// Read an `u16` in big-endian from the `data` starting at the selected address.
+    ///
+    /// Returns `None` without a selection or when there are not enough bytes after the selection.
+    #[must_use]
+    pub fn read_u16_be(&self, data: &[u8]) -> Option<u16> {
+        self.read_selected(data).map(u16::from_be_bytes)
+    }
+
+    /// Read an `u32` in little-endian from the `data` starting at the selected address.
+    ///
+    /// Returns `None` without a selection or when there are not enough bytes after the selection.
+    #[must_use]
+    pub fn read_u32_le(&self, data: &[u8]) -> Option<u32> {
+        self.read_selected(data).map(u32::from_le_bytes)
+    }
+
+    /// Read an `u32` in big-endian from the `data` starting at the selected address.
+    ///
+    /// Returns `None` without a selection or when there are not enough bytes after the selection.
+    #[must_use]
+    pub fn read_u32_be(&self, data: &[u8]) -> Option<u32> {
+        self.read_selected(data).map(u32::from_be_bytes)
+    }
+
+    /// Read an `u64` in little-endian from the `data` starting at the selected address.
+    ///
+    /// Returns `None` without a selection or when there are not enough bytes after the selection.
+    #[must_use]
+    pub fn read_u64_le(&self, data: &[u8]) -> Option<u64> {
+        self.read_selected(data).map(u64::from_le_bytes)
+    }
+
+    /// Read an `u64` in big-endian from the `data` starting at the selected address.
+    ///
+    /// Returns `None` without a selection or when there are not enough bytes after the selection.
+    #[must_use]
+    pub fn read_u64_be(&self, data: &[u8]) -> Option<u64> {
+        self.read_selected(data).map(u64::from_be_bytes)
+    }
+
+    /// Read the `N` bytes starting at the selected address.
+    fn read_selected<const N: usize>(&self, data: &[u8]) -> Option<[u8; N]> {
+        let start = self.selected_address?;
+        let bytes = data.get(start..start.checked_add(N)?)?;
+        bytes.try_into().ok()
+    }
+
     /// Select the given address.
+    /// Selecting `None` also removes the anchor of the [`selected_range()`](Self::selected_range).
     ///
     /// Returns `true` when the selection changed.
     pub fn select_address(&mut self, address: Option<usize>) -> bool {
         self.ensure_selected_in_view_on_next_render = true;
+        if address.is_none() {
+            self.selection_anchor = None;
+        }
 
         // Limit address to what was possible to select on last render
         let address = if let (Some(selected), Some(last)) = (address, self.last_render_positions) {
@@ -45,16 +255,473 @@ impl State {
 
         let changed = self.selected_address != address;
         self.selected_address = address;
+        if changed {
+            self.nibble = Nibble::High;
+        }
         changed
     }
 
+    /// Returns the half of the selected byte the left and right arrow keys are on.
+    ///
+    /// Only relevant with [`set_nibble_navigation()`](Self::set_nibble_navigation).
+    #[must_use]
+    pub const fn selected_nibble(&self) -> Nibble {
+        self.nibble
+    }
+
+    /// Let the left and right arrow keys step through the nibbles of each byte instead of whole bytes.
+    ///
+    /// Useful for editing the hex values in place.
+    /// The active nibble of the selected byte is underlined.
+    /// Defaults to `false`.
+    pub const fn set_nibble_navigation(&mut self, nibble_navigation: bool) {
+        self.nibble_navigation = nibble_navigation;
+        self.nibble = Nibble::High;
+    }
+
+    /// Returns the range of addresses visible on the last render.
+    ///
+    /// Returns `None` before the first render.
+    #[must_use]
+    pub fn visible_address_range(&self) -> Option<Range<usize>> {
+        let positions = self.last_render_positions?;
+        let per_row = usize::from(positions.per_row);
+        let start = self
+            .offset_address
+            .saturating_div(per_row)
+            .saturating_mul(per_row);
+        let end = start
+            .saturating_add(self.last_visible_lines.saturating_mul(per_row))
+            .min(positions.biggest_address.saturating_add(1));
+        Some(start..end)
+    }
+
+    /// Returns the amount of bytes visible on the last render.
+    ///
+    /// A partial last row only counts its actual bytes.
+    /// Returns `0` before the first render.
+    #[must_use]
+    pub fn visible_byte_count(&self) -> usize {
+        self.visible_address_range().map_or(0, |range| range.len())
+    }
+
+    /// Returns the on-screen area of the byte at the given address on the last render.
+    ///
+    /// This is the cell in the hex column or the char column when the hex column is hidden.
+    /// Useful to anchor popups at a byte.
+    ///
+    /// Returns `None` when the address was not visible.
+    #[must_use]
+    pub fn cell_rect(&self, address: usize) -> Option<Rect> {
+        let positions = self.last_render_positions?;
+        let visible = self.visible_address_range()?;
+        if !visible.contains(&address) {
+            return None;
+        }
+        let per_row = usize::from(positions.per_row);
+        let row = u16::try_from((address - visible.start) / per_row).ok()?;
+        let column = u16::try_from(address % per_row).ok()?;
+        let index = column.checked_sub(self.offset_column)?;
+        let visible_columns = positions.visible_columns(self.offset_column);
+        if index >= visible_columns {
+            return None;
+        }
+        let y = positions
+            .inner_area
+            .y
+            .saturating_add(row.saturating_mul(positions.row_height()));
+        let rect = if positions.show_hex_column {
+            Rect::new(
+                positions.x_hex(
+                    self.offset_column,
+                    positions.hex_slot(self.offset_column, visible_columns, index),
+                ),
+                y,
+                positions.byte_width,
+                1,
+            )
+        } else {
+            Rect::new(positions.x_char(index), y, 1, 1)
+        };
+        Some(rect)
+    }
+
+    /// Returns whether the byte at the given address was visible on the last render.
+    ///
+    /// Returns `false` before the first render.
+    #[must_use]
+    pub fn is_visible(&self, address: usize) -> bool {
+        self.cell_rect(address).is_some()
+    }
+
+    /// Pin the current selection as the anchor of the [`selected_range()`](Self::selected_range).
+    ///
+    /// Moving the selection afterwards selects every byte between the anchor and the selection.
+    pub const fn set_anchor(&mut self) {
+        self.selection_anchor = self.selected_address;
+    }
+
+    /// Remove the anchor of the [`selected_range()`](Self::selected_range) and only keep the single selected address.
+    pub const fn clear_anchor(&mut self) {
+        self.selection_anchor = None;
+    }
+
+    /// Returns the range between the anchor and the current selection.
+    ///
+    /// Returns `None` when there is no anchor set via [`set_anchor()`](Self::set_anchor).
+    #[must_use]
+    pub fn selected_range(&self) -> Option<RangeInclusive<usize>> {
+        let selected = self.selected_address?;
+        let anchor = self.selection_anchor?;
+        Some(selected.min(anchor)..=selected.max(anchor))
+    }
+
+    /// Returns the bytes of the [`selected_range()`](Self::selected_range) or the single selected byte when there is no anchor.
+    ///
+    /// The selection is limited to the given `data` so a selection of a previous longer data does not panic.
+    #[must_use]
+    pub fn copy_selection<'a>(&self, data: &'a [u8]) -> Option<&'a [u8]> {
+        let selected = self.selected_address?;
+        let range = self.selected_range().unwrap_or(selected..=selected);
+        let last = data.len().checked_sub(1)?;
+        let start = *range.start();
+        if start > last {
+            return None;
+        }
+        data.get(start..=last.min(*range.end()))
+    }
+
+    /// Returns the scattered addresses selected with [`toggle_selected()`](Self::toggle_selected).
+    #[must_use]
+    pub const fn selection_set(&self) -> &BTreeSet<usize> {
+        &self.selection_set
+    }
+
+    /// Add the address to the selection set or remove it when it is already part of it.
+    ///
+    /// The [`selected_address()`](Self::selected_address) stays the active one for navigation.
+    ///
+    /// Returns `true` when the address is now part of the selection set.
+    pub fn toggle_selected(&mut self, address: usize) -> bool {
+        if self.selection_set.remove(&address) {
+            false
+        } else {
+            self.selection_set.insert(address)
+        }
+    }
+
+    /// Remove all addresses from the selection set.
+    ///
+    /// Returns `true` when the selection set was not empty.
+    pub fn clear_selection_set(&mut self) -> bool {
+        let changed = !self.selection_set.is_empty();
+        self.selection_set.clear();
+        changed
+    }
+
+    /// Returns the bytes of the [`selection_set()`](Self::selection_set) in address order.
+    ///
+    /// Addresses beyond the given `data` are skipped.
+    #[must_use]
+    pub fn copy_selection_set(&self, data: &[u8]) -> Vec<u8> {
+        self.selection_set
+            .iter()
+            .map_while(|address| data.get(*address).copied())
+            .collect()
+    }
+
+    /// Select the given address and bring it into view on the next render.
+    ///
+    /// The address is limited to the data length of the last render when known.
+    ///
+    /// Returns `true` when the selection changed.
+    pub fn goto(&mut self, address: usize) -> bool {
+        self.select_address(Some(address))
+    }
+
+    /// Search for the `needle` in the `data` starting at the address `from`.
+    /// When there is no match until the end of the data the search continues from the start.
+    ///
+    /// On a match the address of the first byte of the match gets selected and brought into view on the next render.
+    /// The `needle` is remembered for [`find_next()`](Self::find_next) and [`find_prev()`](Self::find_prev).
+    ///
+    /// Returns the address of the match.
+    pub fn find(&mut self, data: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+        needle.clone_into(&mut self.last_needle);
+        self.last_needle_ignore_case = false;
+        let address = find_forward(data, needle, from).or_else(|| find_forward(data, needle, 0))?;
+        self.select_address(Some(address));
+        Some(address)
+    }
+
+    /// Search for the ASCII `text` in the `data` like [`find()`](Self::find).
+    ///
+    /// An empty `text` never matches.
+    ///
+    /// Returns the address of the match which is now selected.
+    pub fn find_ascii(&mut self, data: &[u8], text: &str, from: usize) -> Option<usize> {
+        self.find(data, text.as_bytes(), from)
+    }
+
+    /// Search for the ASCII `text` in the `data` like [`find_ascii()`](Self::find_ascii) while ignoring the ASCII case.
+    ///
+    /// [`find_next()`](Self::find_next) and [`find_prev()`](Self::find_prev) keep ignoring the case for this `text`.
+    ///
+    /// Returns the address of the match which is now selected.
+    pub fn find_ascii_ignore_case(
+        &mut self,
+        data: &[u8],
+        text: &str,
+        from: usize,
+    ) -> Option<usize> {
+        text.as_bytes().clone_into(&mut self.last_needle);
+        self.last_needle_ignore_case = true;
+        let needle = text.as_bytes();
+        let matches = |window: &[u8]| window.eq_ignore_ascii_case(needle);
+        let address = find_forward_by(data, needle.len(), from, matches)
+            .or_else(|| find_forward_by(data, needle.len(), 0, matches))?;
+        self.select_address(Some(address));
+        Some(address)
+    }
+
+    /// Search for the last needle of [`find()`](Self::find) or [`find_ascii_ignore_case()`](Self::find_ascii_ignore_case) after the current selection.
+    /// When there is no match until the end of the data the search continues from the start.
+    ///
+    /// Returns the address of the match which is now selected.
+    pub fn find_next(&mut self, data: &[u8]) -> Option<usize> {
+        let needle = self.last_needle.as_slice();
+        let matches = self.last_needle_matcher();
+        let from = self
+            .selected_address
+            .map_or(0, |selected| selected.saturating_add(1));
+        let address = find_forward_by(data, needle.len(), from, matches)
+            .or_else(|| find_forward_by(data, needle.len(), 0, matches))?;
+        self.select_address(Some(address));
+        Some(address)
+    }
+
+    /// Search for the last needle of [`find()`](Self::find) or [`find_ascii_ignore_case()`](Self::find_ascii_ignore_case) before the current selection.
+    /// When there is no match until the start of the data the search continues from the end.
+    ///
+    /// Returns the address of the match which is now selected.
+    pub fn find_prev(&mut self, data: &[u8]) -> Option<usize> {
+        let length = self.last_needle.len();
+        let matches = self.last_needle_matcher();
+        let address = self
+            .selected_address
+            .and_then(|selected| selected.checked_sub(1))
+            .and_then(|before| find_backward_by(data, length, before, matches))
+            .or_else(|| find_backward_by(data, length, usize::MAX, matches))?;
+        self.select_address(Some(address));
+        Some(address)
+    }
+
+    /// Returns whether a window of the data matches the last needle in the case mode it was searched with.
+    fn last_needle_matcher(&self) -> impl Fn(&[u8]) -> bool + Copy + '_ {
+        let needle = self.last_needle.as_slice();
+        let ignore_case = self.last_needle_ignore_case;
+        move |window: &[u8]| {
+            if ignore_case {
+                window.eq_ignore_ascii_case(needle)
+            } else {
+                window == needle
+            }
+        }
+    }
+
+    /// Move the view on the next render so the selected address is visible again.
+    ///
+    /// The selection itself stays unchanged.
+    pub const fn scroll_to_selection(&mut self) {
+        self.ensure_selected_in_view_on_next_render = true;
+    }
+
+    /// Move the view on the next render so the selected address is in the vertical center.
+    pub const fn center_selection(&mut self) {
+        self.ensure_selected_in_view_on_next_render = true;
+        self.center_selected_on_next_render = true;
+    }
+
+    /// Capture the scroll position and the selection to return to them later with [`restore_view`](Self::restore_view).
+    pub const fn bookmark_view(&self) -> ViewToken {
+        ViewToken {
+            offset_address: self.offset_address,
+            selected_address: self.selected_address,
+        }
+    }
+
+    /// Return to the scroll position and the selection captured by [`bookmark_view`](Self::bookmark_view).
+    ///
+    /// Both are limited to the data on the next render which also brings the selection into view.
+    pub const fn restore_view(&mut self, token: ViewToken) {
+        self.offset_address = token.offset_address;
+        self.selected_address = token.selected_address;
+        self.ensure_selected_in_view_on_next_render = true;
+    }
+
+    /// Returns the bookmarked addresses in ascending order.
+    #[must_use]
+    pub fn bookmarks(&self) -> &[usize] {
+        &self.bookmarks
+    }
+
+    /// Bookmark the given address.
+    ///
+    /// Returns `true` when the address was not bookmarked before.
+    pub fn add_bookmark(&mut self, address: usize) -> bool {
+        match self.bookmarks.binary_search(&address) {
+            Ok(_) => false,
+            Err(index) => {
+                self.bookmarks.insert(index, address);
+                true
+            }
+        }
+    }
+
+    /// Remove the bookmark of the given address.
+    ///
+    /// Returns `true` when the address was bookmarked before.
+    pub fn remove_bookmark(&mut self, address: usize) -> bool {
+        match self.bookmarks.binary_search(&address) {
+            Ok(index) => {
+                self.bookmarks.remove(index);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Select the next bookmark after the current selection.
+    /// After the last bookmark the first one is selected.
+    ///
+    /// Returns `true` when the selection changed.
+    pub fn next_bookmark(&mut self) -> bool {
+        let next = self
+            .selected_address
+            .and_then(|selected| self.bookmarks.iter().find(|bookmark| **bookmark > selected))
+            .or_else(|| self.bookmarks.first())
+            .copied();
+        next.is_some_and(|address| self.select_address(Some(address)))
+    }
+
+    /// Select the previous bookmark before the current selection.
+    /// Before the first bookmark the last one is selected.
+    ///
+    /// Returns `true` when the selection changed.
+    pub fn prev_bookmark(&mut self) -> bool {
+        let prev = self
+            .selected_address
+            .and_then(|selected| {
+                self.bookmarks
+                    .iter()
+                    .rev()
+                    .find(|bookmark| **bookmark < selected)
+            })
+            .or_else(|| self.bookmarks.last())
+            .copied();
+        prev.is_some_and(|address| self.select_address(Some(address)))
+    }
+
+    /// Select the next printable ASCII byte after the current selection.
+    /// Without a selection the search starts at the first byte.
+    ///
+    /// Returns `true` when the selection changed.
+    /// When there is no printable byte after the selection it stays unchanged.
+    pub fn select_next_printable(&mut self, data: &[u8]) -> bool {
+        let start = self
+            .selected_address
+            .map_or(0, |selected| selected.saturating_add(1));
+        let next = data
+            .get(start..)
+            .and_then(|after| after.iter().position(u8::is_ascii_graphic))
+            .map(|position| start.saturating_add(position));
+        next.is_some_and(|address| self.select_address(Some(address)))
+    }
+
+    /// Select the previous printable ASCII byte before the current selection.
+    /// Without a selection the search starts at the last byte.
+    ///
+    /// Returns `true` when the selection changed.
+    /// When there is no printable byte before the selection it stays unchanged.
+    pub fn select_prev_printable(&mut self, data: &[u8]) -> bool {
+        let end = self
+            .selected_address
+            .map_or(data.len(), |selected| selected.min(data.len()));
+        let prev = data[..end].iter().rposition(u8::is_ascii_graphic);
+        prev.is_some_and(|address| self.select_address(Some(address)))
+    }
+
+    /// Returns the first line of data shown when rendered with the given positions.
+    pub(crate) fn start_line(&self, positions: RenderPositions, available_height: usize) -> usize {
+        let per_row = usize::from(positions.per_row);
+        let offset_address = self.offset_address.min(positions.biggest_address);
+        let mut start_line = offset_address.saturating_div(per_row);
+        if !self.ensure_selected_in_view_on_next_render {
+            return start_line;
+        }
+        if let Some(selected_address) = self.selected_address {
+            let selected_address = selected_address.min(positions.biggest_address);
+            let selected_line = selected_address.saturating_div(per_row);
+            if self.center_selected_on_next_render {
+                start_line = selected_line.saturating_sub(available_height.saturating_div(2));
+            } else if selected_line < start_line {
+                // Move offset up
+                start_line = selected_line;
+            } else {
+                let end_line = start_line.saturating_add(available_height);
+                if selected_line >= end_line {
+                    // Move offset down
+                    let end_line = selected_line.saturating_add(1);
+                    start_line = end_line.saturating_sub(available_height);
+                }
+            }
+        }
+        start_line
+    }
+
+    /// Returns the amount of bytes per row on the last render.
+    ///
+    /// Returns `None` before the first render.
+    #[must_use]
+    pub fn bytes_per_row(&self) -> Option<u16> {
+        self.last_render_positions
+            .map(|positions| positions.per_row)
+    }
+
+    /// Returns the width of the address column without the `: ` on the last render.
+    ///
+    /// Returns `None` before the first render.
+    #[must_use]
+    pub fn address_width(&self) -> Option<u16> {
+        self.last_render_positions
+            .map(|positions| positions.address_width)
+    }
+
     /// Returns the amount of addresses shown per row on last render
     #[must_use]
-    fn last_per_row(&self) -> usize {
+    pub(crate) fn last_per_row(&self) -> usize {
         self.last_render_positions
             .map_or(8, |positions| usize::from(positions.per_row))
     }
 
+    /// Select the first byte of the data.
+    ///
+    /// Returns `true` when the selection changed.
+    pub fn select_first(&mut self) -> bool {
+        self.select_address(Some(0))
+    }
+
+    /// Select the last byte of data with the given length.
+    /// Without data nothing is changed.
+    ///
+    /// Returns `true` when the selection changed.
+    pub fn select_last(&mut self, data_len: usize) -> bool {
+        let Some(last) = data_len.checked_sub(1) else {
+            return false;
+        };
+        self.select_address(Some(last))
+    }
+
     /// Handles the Home key.
     ///
     /// Returns `true` when the selection changed.
@@ -86,77 +753,352 @@ impl State {
     ///
     /// Returns `true` when the selection changed.
     pub fn key_up(&mut self) -> bool {
-        self.select_address(Some(self.selected_address.map_or(usize::MAX, |selected| {
-            let per_row = self.last_per_row();
-            selected.saturating_sub(per_row)
-        })))
+        let per_row = self.last_per_row();
+        let address = match (self.selected_address, self.wrap_biggest_address()) {
+            (None, _) => usize::MAX,
+            (Some(selected), Some(biggest)) if selected < per_row => {
+                let last_row_start = biggest - biggest % per_row;
+                let address = last_row_start.saturating_add(selected);
+                if address > biggest {
+                    address.saturating_sub(per_row)
+                } else {
+                    address
+                }
+            }
+            (Some(selected), _) => selected.saturating_sub(per_row),
+        };
+        self.select_address(Some(address))
     }
 
     /// Handles the down arrow key.
     ///
     /// Returns `true` when the selection changed.
     pub fn key_down(&mut self) -> bool {
-        self.select_address(Some(self.selected_address.map_or(0, |selected| {
-            let per_row = self.last_per_row();
-            selected.saturating_add(per_row)
-        })))
+        let per_row = self.last_per_row();
+        let address = match (self.selected_address, self.wrap_biggest_address()) {
+            (None, _) => 0,
+            (Some(selected), Some(biggest)) if selected.saturating_add(per_row) > biggest => {
+                selected % per_row
+            }
+            (Some(selected), _) => selected.saturating_add(per_row),
+        };
+        self.select_address(Some(address))
     }
 
     /// Handles the left arrow key.
     ///
     /// Returns `true` when the selection changed.
     pub fn key_left(&mut self) -> bool {
-        self.select_address(Some(
-            self.selected_address
-                .map_or(usize::MAX, |selected| selected.saturating_sub(1)),
-        ))
+        if self.nibble_navigation && self.selected_address.is_some() && self.nibble == Nibble::Low {
+            self.nibble = Nibble::High;
+            self.ensure_selected_in_view_on_next_render = true;
+            return true;
+        }
+        let address = match (self.selected_address, self.wrap_biggest_address()) {
+            (None, _) => usize::MAX,
+            (Some(0), Some(biggest)) => biggest,
+            (Some(selected), _) => selected.saturating_sub(1),
+        };
+        let changed = self.select_address(Some(address));
+        if changed && self.nibble_navigation {
+            self.nibble = Nibble::Low;
+        }
+        changed
     }
 
     /// Handles the right arrow key.
     ///
     /// Returns `true` when the selection changed.
     pub fn key_right(&mut self) -> bool {
-        self.select_address(Some(
-            self.selected_address
-                .map_or(0, |selected| selected.saturating_add(1)),
-        ))
+        if self.nibble_navigation && self.selected_address.is_some() && self.nibble == Nibble::High
+        {
+            self.nibble = Nibble::Low;
+            self.ensure_selected_in_view_on_next_render = true;
+            return true;
+        }
+        let address = match (self.selected_address, self.wrap_biggest_address()) {
+            (None, _) => 0,
+            (Some(selected), Some(biggest)) if selected >= biggest => 0,
+            (Some(selected), _) => selected.saturating_add(1),
+        };
+        self.select_address(Some(address))
+    }
+
+    /// Handles the up arrow key like [`key_up()`](Self::key_up) and returns what happened.
+    pub fn key_up_outcome(&mut self) -> NavOutcome {
+        NavOutcome::moved_or(self.key_up(), NavOutcome::StartReached)
+    }
+
+    /// Handles the down arrow key like [`key_down()`](Self::key_down) and returns what happened.
+    pub fn key_down_outcome(&mut self) -> NavOutcome {
+        NavOutcome::moved_or(self.key_down(), NavOutcome::EndReached)
+    }
+
+    /// Handles the left arrow key like [`key_left()`](Self::key_left) and returns what happened.
+    pub fn key_left_outcome(&mut self) -> NavOutcome {
+        NavOutcome::moved_or(self.key_left(), NavOutcome::StartReached)
+    }
+
+    /// Handles the right arrow key like [`key_right()`](Self::key_right) and returns what happened.
+    pub fn key_right_outcome(&mut self) -> NavOutcome {
+        NavOutcome::moved_or(self.key_right(), NavOutcome::EndReached)
+    }
+
+    /// Wrap the arrow keys around at the start and the end of the data.
+    ///
+    /// Wrapping works once the data length is known from the first render.
+    /// Defaults to `false` which stops at the start and the end.
+    pub const fn set_wrap_navigation(&mut self, wrap_navigation: bool) {
+        self.wrap_navigation = wrap_navigation;
+    }
+
+    /// Biggest address of the last render when the navigation should wrap around
+    fn wrap_biggest_address(&self) -> Option<usize> {
+        if !self.wrap_navigation {
+            return None;
+        }
+        self.last_render_positions
+            .map(|positions| positions.biggest_address)
+    }
+
+    /// Amount of addresses on a page of the last render
+    fn last_page_size(&self) -> usize {
+        self.last_available_height
+            .max(1)
+            .saturating_mul(self.last_per_row())
+    }
+
+    /// Amount of addresses on half a page of the last render, at least a single row
+    fn last_half_page_size(&self) -> usize {
+        (self.last_available_height / 2)
+            .max(1)
+            .saturating_mul(self.last_per_row())
+    }
+
+    /// Move the selection down by the height of the last render.
+    /// Without a selection the first visible address is selected.
+    ///
+    /// In contrast to [`scroll_down()`](Self::scroll_down) this moves the selection and the view follows it.
+    ///
+    /// Returns the new selected address.
+    pub fn page_down(&mut self) -> usize {
+        self.move_selection_down(self.last_page_size())
+    }
+
+    /// Move the selection up by the height of the last render.
+    /// Without a selection the first visible address is selected.
+    ///
+    /// In contrast to [`scroll_up()`](Self::scroll_up) this moves the selection and the view follows it.
+    ///
+    /// Returns the new selected address.
+    pub fn page_up(&mut self) -> usize {
+        self.move_selection_up(self.last_page_size())
+    }
+
+    /// Move the selection down by half the height of the last render.
+    /// Without a selection the first visible address is selected.
+    ///
+    /// In contrast to [`scroll_down()`](Self::scroll_down) this moves the selection and the view follows it.
+    ///
+    /// Returns the new selected address.
+    pub fn half_page_down(&mut self) -> usize {
+        self.move_selection_down(self.last_half_page_size())
+    }
+
+    /// Move the selection up by half the height of the last render.
+    /// Without a selection the first visible address is selected.
+    ///
+    /// In contrast to [`scroll_up()`](Self::scroll_up) this moves the selection and the view follows it.
+    ///
+    /// Returns the new selected address.
+    pub fn half_page_up(&mut self) -> usize {
+        self.move_selection_up(self.last_half_page_size())
+    }
+
+    pub(crate) fn move_selection_down(&mut self, addresses: usize) -> usize {
+        let address = self
+            .selected_address
+            .map_or(self.offset_address, |selected| {
+                selected.saturating_add(addresses)
+            });
+        self.select_address(Some(address));
+        self.selected_address.unwrap_or(address)
+    }
+
+    pub(crate) fn move_selection_up(&mut self, addresses: usize) -> usize {
+        let address = self
+            .selected_address
+            .map_or(self.offset_address, |selected| {
+                selected.saturating_sub(addresses)
+            });
+        self.select_address(Some(address));
+        self.selected_address.unwrap_or(address)
+    }
+
+    /// Scroll to the end of the data on the next render whenever the data grew since the last render.
+    ///
+    /// Useful to follow a growing capture like `tail -f`.
+    /// Scrolling up with [`scroll_up()`](Self::scroll_up) disables following.
+    /// Defaults to `false`.
+    pub const fn set_follow(&mut self, follow: bool) {
+        self.follow = follow;
+    }
+
+    /// Returns whether the view follows the end of growing data, see [`set_follow()`](Self::set_follow).
+    #[must_use]
+    pub const fn is_following(&self) -> bool {
+        self.follow
     }
 
     /// Scroll the specified amount of lines up
     ///
+    /// This stops [following](Self::set_follow) the end of the data.
+    ///
     /// Returns `true` when the offset changed
     /// Returns `false` when the scrolling has reached the top.
     pub fn scroll_up(&mut self, lines: usize) -> bool {
+        self.follow = false;
         let before = self.offset_address;
         self.offset_address = self
             .offset_address
             .saturating_sub(lines.saturating_mul(self.last_per_row()));
+        self.snap_offset_to_row();
+        self.keep_selection_in_view();
         before != self.offset_address
     }
 
     /// Scroll the specified amount of lines down
     ///
-    /// In contrast to [`scroll_up()`](Self::scroll_up) this can not return whether the view position changed or not as the actual change is determined on render.
-    /// Always returns `true`.
+    /// Scrolling stops when the last line of data is at the bottom of the view of the last render.
+    /// Before the first render there is no limit.
+    ///
+    /// Returns `true` when the view position changed.
     pub fn scroll_down(&mut self, lines: usize) -> bool {
         let before = self.offset_address;
-        let last_biggest_address = self
-            .last_render_positions
-            .map_or(usize::MAX, |positions| positions.biggest_address);
+        let max_offset_address = self.last_render_positions.map_or(usize::MAX, |positions| {
+            positions
+                .available_data_lines
+                .saturating_sub(self.last_available_height)
+                .saturating_mul(usize::from(positions.per_row))
+        });
         self.offset_address = self
             .offset_address
             .saturating_add(lines.saturating_mul(self.last_per_row()))
-            .min(last_biggest_address);
+            .min(max_offset_address);
+        self.snap_offset_to_row();
+        self.keep_selection_in_view();
         before != self.offset_address
     }
 
+    /// Move the offset back to the start of its row.
+    fn snap_offset_to_row(&mut self) {
+        self.offset_address -= self.offset_address % self.last_per_row();
+    }
+
+    /// Scroll up like [`scroll_up()`](Self::scroll_up) and returns what happened.
+    pub fn scroll_up_outcome(&mut self, lines: usize) -> NavOutcome {
+        NavOutcome::scrolled_or(self.scroll_up(lines), NavOutcome::StartReached)
+    }
+
+    /// Scroll down like [`scroll_down()`](Self::scroll_down) and returns what happened.
+    pub fn scroll_down_outcome(&mut self, lines: usize) -> NavOutcome {
+        NavOutcome::scrolled_or(self.scroll_down(lines), NavOutcome::EndReached)
+    }
+
+    /// Move the selection along when scrolling it out of view.
+    ///
+    /// The selection stays in its column and moves to the nearest row visible with the height of the last render.
+    /// Defaults to `false` which keeps the selection where it is.
+    pub const fn set_selection_follows_scroll(&mut self, selection_follows_scroll: bool) {
+        self.selection_follows_scroll = selection_follows_scroll;
+    }
+
+    fn keep_selection_in_view(&mut self) {
+        let Some(selected) = self.selected_address else {
+            return;
+        };
+        if !self.selection_follows_scroll {
+            return;
+        }
+        let per_row = self.last_per_row();
+        let first_row = self.offset_address / per_row;
+        let last_row = first_row.saturating_add(self.last_available_height.max(1) - 1);
+        let row = selected / per_row;
+        let visible_row = row.clamp(first_row, last_row);
+        if visible_row != row {
+            let mut address = visible_row
+                .saturating_mul(per_row)
+                .saturating_add(selected % per_row);
+            if let Some(positions) = self.last_render_positions {
+                address = address.min(positions.biggest_address);
+            }
+            self.selected_address = Some(address);
+        }
+    }
+
+    /// Scroll the specified amount of byte columns to the left
+    ///
+    /// Only relevant when the [`bytes_per_row`](crate::BinaryDataWidget::bytes_per_row) do not fit into the area.
+    ///
+    /// Returns `true` when the offset changed
+    /// Returns `false` when the scrolling has reached the left end.
+    pub const fn scroll_left(&mut self, columns: u16) -> bool {
+        let before = self.offset_column;
+        self.offset_column = self.offset_column.saturating_sub(columns);
+        before != self.offset_column
+    }
+
+    /// Scroll the specified amount of byte columns to the right
+    ///
+    /// Only relevant when the [`bytes_per_row`](crate::BinaryDataWidget::bytes_per_row) do not fit into the area.
+    ///
+    /// Returns `true` when the offset changed
+    /// Returns `false` when the scrolling has reached the right end on the last render.
+    pub fn scroll_right(&mut self, columns: u16) -> bool {
+        let before = self.offset_column;
+        let max_offset_column = self
+            .last_render_positions
+            .map_or(u16::MAX, |positions| positions.max_offset_column());
+        self.offset_column = self
+            .offset_column
+            .saturating_add(columns)
+            .min(max_offset_column);
+        before != self.offset_column
+    }
+
     /// Get the address on the given display position of last render
+    ///
+    /// Returns `None` when the position is after the end of the data.
     #[must_use]
     pub fn clicked_address(&self, column: u16, row: u16) -> Option<usize> {
-        let address = self
-            .last_render_positions?
-            .address_at(self.offset_address, column, row);
-        Some(address)
+        self.last_render_positions?
+            .address_at(self.offset_address, self.offset_column, column, row)
+    }
+
+    /// Remember the address on the given display position of last render as hovered.
+    /// Useful for mouse movements, see [`hover_style`](crate::BinaryDataWidget::hover_style).
+    ///
+    /// Positions without data, for example after the mouse left the widget, clear the hovered address.
+    ///
+    /// Returns `true` when the hovered address changed.
+    pub fn hover_at(&mut self, column: u16, row: u16) -> bool {
+        let hovered = self
+            .last_render_positions
+            .filter(|positions| {
+                positions
+                    .inner_area
+                    .contains(Position { x: column, y: row })
+            })
+            .and_then(|_| self.clicked_address(column, row));
+        let changed = self.hovered_address != hovered;
+        self.hovered_address = hovered;
+        changed
+    }
+
+    /// Returns the address under the mouse, see [`hover_at()`](Self::hover_at).
+    #[must_use]
+    pub const fn hovered_address(&self) -> Option<usize> {
+        self.hovered_address
     }
 
     /// Select the address on the given display position of last render.
@@ -164,11 +1106,998 @@ impl State {
     ///
     /// Returns `true` when the selection changed
     pub fn select_at(&mut self, column: u16, row: u16) -> bool {
-        #[allow(clippy::option_if_let_else)]
-        if let Some(address) = self.clicked_address(column, row) {
-            self.select_address(Some(address))
-        } else {
-            false
+        self.clicked_address(column, row)
+            .is_some_and(|address| self.select_address(Some(address)))
+    }
+
+    /// Extend the selection to the address on the given display position of last render.
+    /// Useful for mouse drags.
+    ///
+    /// Sets the anchor at the current selection when there is none yet.
+    ///
+    /// Returns `true` when the selection changed
+    pub fn drag_to(&mut self, column: u16, row: u16) -> bool {
+        let Some(address) = self.clicked_address(column, row) else {
+            return false;
+        };
+        if self.selection_anchor.is_none() {
+            self.set_anchor();
         }
+        self.select_address(Some(address))
+    }
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_bookmarks<'de, D>(deserializer: D) -> Result<Vec<usize>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let mut bookmarks = <Vec<usize> as serde::Deserialize>::deserialize(deserializer)?;
+    bookmarks.sort_unstable();
+    bookmarks.dedup();
+    Ok(bookmarks)
+}
+
+/// Returns the address of the first match of `needle` at or after `from`
+fn find_forward(data: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    find_forward_by(data, needle.len(), from, |window| window == needle)
+}
+
+/// Returns the address of the first window of `length` at or after `from` which `matches`
+fn find_forward_by<F>(data: &[u8], length: usize, from: usize, matches: F) -> Option<usize>
+where
+    F: Fn(&[u8]) -> bool,
+{
+    if length == 0 {
+        return None;
+    }
+    data.get(from..)?
+        .windows(length)
+        .position(matches)
+        .map(|position| position.saturating_add(from))
+}
+
+/// Returns the address of the last window of `length` at or before `before` which `matches`
+fn find_backward_by<F>(data: &[u8], length: usize, before: usize, matches: F) -> Option<usize>
+where
+    F: Fn(&[u8]) -> bool,
+{
+    if length == 0 {
+        return None;
+    }
+    data.windows(length)
+        .take(before.saturating_add(1))
+        .rposition(matches)
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::buffer::Buffer;
+    use ratatui::style::{Color, Style};
+    use ratatui::widgets::StatefulWidget;
+
+    use super::*;
+    use crate::{BinaryDataWidget, ByteOrder};
+
+    fn rendered(data_length: usize) -> State {
+        let mut state = State::new();
+        state.last_render_positions =
+            RenderPositions::new(Rect::new(0, 0, 19, 5), data_length, LayoutOptions::new());
+        state
+    }
+
+    fn render(state: &mut State, data: &[u8], width: u16, height: u16) {
+        let area = Rect::new(0, 0, width, height);
+        let mut buffer = Buffer::empty(area);
+        StatefulWidget::render(BinaryDataWidget::new(data), area, &mut buffer, state);
+    }
+
+    #[test]
+    fn goto() {
+        let mut state = rendered(0x20);
+        assert!(state.goto(5));
+        assert_eq!(state.selected_address(), Some(5));
+        assert!(state.ensure_selected_in_view_on_next_render);
+        assert!(!state.goto(5));
+    }
+
+    #[test]
+    fn view_token_round_trip() {
+        let data = [0; 0x100];
+        let mut state = State::new();
+        render(&mut state, &data, 19, 5);
+        state.scroll_down(10);
+        state.select_address(Some(0x2a));
+        render(&mut state, &data, 19, 5);
+        let token = state.bookmark_view();
+
+        let mut restored = State::new();
+        restored.restore_view(token);
+        assert!(restored.ensure_selected_in_view_on_next_render);
+        render(&mut restored, &data, 19, 5);
+        assert_eq!(restored.bookmark_view(), token);
+        assert_eq!(restored.get_offset_address(), state.get_offset_address());
+        assert_eq!(restored.selected_address(), Some(0x2a));
+    }
+
+    #[test]
+    fn goto_is_clamped() {
+        let mut state = rendered(0x20);
+        assert!(state.goto(0x100));
+        assert_eq!(state.selected_address(), Some(0x1f));
+    }
+
+    #[test]
+    fn goto_before_render() {
+        let mut state = State::new();
+        assert!(state.goto(0x100));
+        assert_eq!(state.selected_address(), Some(0x100));
+    }
+
+    #[test]
+    fn selected_value() {
+        let mut state = State::new();
+        assert_eq!(state.selected_value(b"ABC"), None);
+        state.select_address(Some(1));
+        assert_eq!(state.selected_value(b"ABC"), Some(b'B'));
+        assert_eq!(state.selected_value(b""), None);
+    }
+
+    #[test]
+    fn selected_value_clamps_stale_selection() {
+        let mut state = State::new();
+        state.select_address(Some(0x100));
+        assert_eq!(state.selected_value(b"ABC"), Some(b'C'));
+    }
+
+    #[test]
+    fn read_multi_byte_values() {
+        let data = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09];
+        let mut state = State::new();
+        assert_eq!(state.read_u16_le(&data), None);
+        state.select_address(Some(1));
+        assert_eq!(state.read_u16_le(&data), Some(0x0302));
+        assert_eq!(state.read_u16_be(&data), Some(0x0203));
+        assert_eq!(state.read_u32_le(&data), Some(0x0504_0302));
+        assert_eq!(state.read_u32_be(&data), Some(0x0203_0405));
+        assert_eq!(state.read_u64_le(&data), Some(0x0908_0706_0504_0302));
+        assert_eq!(state.read_u64_be(&data), Some(0x0203_0405_0607_0809));
+    }
+
+    #[test]
+    fn read_multi_byte_values_at_end() {
+        let data = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+        let mut state = State::new();
+        state.select_address(Some(4));
+        assert_eq!(state.read_u32_le(&data), Some(0x0807_0605));
+        assert_eq!(state.read_u64_le(&data), None);
+        state.select_address(Some(7));
+        assert_eq!(state.read_u16_be(&data), None);
+        state.select_address(Some(usize::MAX));
+        assert_eq!(state.read_u16_le(&data), None);
+    }
+
+    #[test]
+    fn find_found() {
+        let mut state = State::new();
+        assert_eq!(state.find(b"abcabc", b"bc", 0), Some(1));
+        assert_eq!(state.selected_address(), Some(1));
+        assert!(state.ensure_selected_in_view_on_next_render);
+        assert_eq!(state.find(b"abcabc", b"bc", 2), Some(4));
+        assert_eq!(state.selected_address(), Some(4));
+    }
+
+    #[test]
+    fn find_overlapping() {
+        let mut state = State::new();
+        assert_eq!(state.find(b"aaaa", b"aa", 1), Some(1));
+        assert_eq!(state.find(b"aaaa", b"aa", 2), Some(2));
+    }
+
+    #[test]
+    fn find_not_found() {
+        let mut state = State::new();
+        state.select_address(Some(2));
+        assert_eq!(state.find(b"abcabc", b"cb", 0), None);
+        assert_eq!(state.find(b"abcabc", b"", 0), None);
+        assert_eq!(state.find(b"ab", b"abc", 0), None);
+        assert_eq!(state.selected_address(), Some(2));
+    }
+
+    #[test]
+    fn find_wraps_to_start() {
+        let mut state = State::new();
+        assert_eq!(state.find(b"abcdef", b"bc", 3), Some(1));
+        assert_eq!(state.find(b"abcdef", b"bc", 100), Some(1));
+        assert_eq!(state.selected_address(), Some(1));
+    }
+
+    #[test]
+    fn find_next_wraps() {
+        let data = b"xabxxabx";
+        let mut state = State::new();
+        assert_eq!(state.find(data, b"ab", 0), Some(1));
+        assert_eq!(state.find_next(data), Some(5));
+        assert_eq!(state.find_next(data), Some(1));
+        assert_eq!(state.selected_address(), Some(1));
+    }
+
+    #[test]
+    fn find_prev_wraps() {
+        let data = b"xabxxabx";
+        let mut state = State::new();
+        assert_eq!(state.find(data, b"ab", 0), Some(1));
+        assert_eq!(state.find_prev(data), Some(5));
+        assert_eq!(state.find_prev(data), Some(1));
+        assert_eq!(state.selected_address(), Some(1));
+    }
+
+    #[test]
+    fn find_next_without_needle() {
+        let mut state = State::new();
+        assert_eq!(state.find_next(b"abc"), None);
+        assert_eq!(state.find_prev(b"abc"), None);
+        assert_eq!(state.selected_address(), None);
+    }
+
+    #[test]
+    fn find_ascii() {
+        let data = b"\0\x01Hello World\xff";
+        let mut state = State::new();
+        assert_eq!(state.find_ascii(data, "Hello", 0), Some(2));
+        assert_eq!(state.selected_address(), Some(2));
+        assert_eq!(state.find_ascii(data, "o", 5), Some(6));
+        assert_eq!(state.find_ascii(data, "hello", 0), None);
+        assert_eq!(state.find_ascii(data, "", 0), None);
+        assert_eq!(state.selected_address(), Some(6));
+    }
+
+    #[test]
+    fn find_ascii_ignore_case() {
+        let data = b"\0\x01Hello World\xff";
+        let mut state = State::new();
+        assert_eq!(state.find_ascii_ignore_case(data, "hELLO", 0), Some(2));
+        assert_eq!(state.selected_address(), Some(2));
+        assert_eq!(state.find_ascii_ignore_case(data, "WORLD", 3), Some(8));
+        assert_eq!(state.find_ascii_ignore_case(data, "", 0), None);
+        assert_eq!(state.selected_address(), Some(8));
+    }
+
+    #[test]
+    fn find_next_keeps_ignoring_case() {
+        let data = b"xAbxxaBxxab";
+        let mut state = State::new();
+        assert_eq!(state.find_ascii_ignore_case(data, "ab", 0), Some(1));
+        assert_eq!(state.find_next(data), Some(5));
+        assert_eq!(state.find_next(data), Some(9));
+        assert_eq!(state.find_prev(data), Some(5));
+
+        assert_eq!(state.find_ascii(data, "ab", 0), Some(9));
+        assert_eq!(state.find_next(data), Some(9));
+        assert_eq!(state.find_prev(data), Some(9));
+    }
+
+    #[test]
+    fn selected_range() {
+        let mut state = State::new();
+        assert_eq!(state.selected_range(), None);
+        state.select_address(Some(5));
+        assert_eq!(state.selected_range(), None);
+        state.set_anchor();
+        assert_eq!(state.selected_range(), Some(5..=5));
+        state.select_address(Some(8));
+        assert_eq!(state.selected_range(), Some(5..=8));
+        state.select_address(Some(2));
+        assert_eq!(state.selected_range(), Some(2..=5));
+        state.clear_anchor();
+        assert_eq!(state.selected_range(), None);
+    }
+
+    #[test]
+    fn unselect_drops_anchor() {
+        let mut state = State::new();
+        state.select_address(Some(5));
+        state.set_anchor();
+        state.select_address(None);
+        state.select_address(Some(8));
+        assert_eq!(state.selected_range(), None);
+    }
+
+    #[test]
+    fn copy_selection_single_byte() {
+        let mut state = State::new();
+        assert_eq!(state.copy_selection(b"Hello"), None);
+        state.select_address(Some(1));
+        assert_eq!(state.copy_selection(b"Hello"), Some(&b"e"[..]));
+        state.select_address(Some(10));
+        assert_eq!(state.copy_selection(b"Hello"), None);
+    }
+
+    #[test]
+    fn copy_selection_range() {
+        let mut state = State::new();
+        state.select_address(Some(3));
+        state.set_anchor();
+        state.select_address(Some(1));
+        assert_eq!(state.copy_selection(b"Hello"), Some(&b"ell"[..]));
+        state.select_address(Some(10));
+        assert_eq!(state.copy_selection(b"Hello"), Some(&b"lo"[..]));
+        assert_eq!(state.copy_selection(b"He"), None);
+        assert_eq!(state.copy_selection(b""), None);
+    }
+
+    #[test]
+    fn visible_address_range() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let mut state = State::new();
+        assert_eq!(state.visible_address_range(), None);
+
+        render(&mut state, &data, 19, 5);
+        assert_eq!(state.visible_address_range(), Some(0..0x14));
+
+        state.scroll_down(14);
+        render(&mut state, &data, 19, 5);
+        assert_eq!(state.visible_address_range(), Some(0x2c..0x40));
+    }
+
+    #[test]
+    fn render_snaps_offset_to_row() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let mut state = State::new();
+        state.offset_address = 5;
+        render(&mut state, &data, 19, 3);
+        assert_eq!(state.get_offset_address(), 4);
+        assert_eq!(state.visible_address_range(), Some(4..0x10));
+    }
+
+    #[test]
+    fn scroll_down_stops_at_last_full_view() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let mut state = State::new();
+        render(&mut state, &data, 19, 5);
+        assert!(state.scroll_down(10));
+        assert_eq!(state.get_offset_address(), 0x28);
+        assert!(state.scroll_down(1));
+        assert_eq!(state.get_offset_address(), 0x2c);
+        assert!(!state.scroll_down(1));
+        render(&mut state, &data, 19, 5);
+        assert_eq!(state.last_visible_lines, 5);
+        assert_eq!(state.visible_address_range(), Some(0x2c..0x40));
+    }
+
+    #[test]
+    fn scroll_down_snaps_offset_to_row() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let mut state = State::new();
+        render(&mut state, &data, 19, 3);
+        state.scroll_down(100);
+        assert_eq!(state.get_offset_address(), 8);
+        state.offset_address = 7;
+        state.scroll_up(1);
+        assert_eq!(state.get_offset_address(), 0);
+    }
+
+    #[test]
+    fn follow_growing_data() {
+        let data: Vec<u8> = (0..=0xff).collect();
+        let mut state = State::new();
+        state.set_follow(true);
+        render(&mut state, &data[..0x10], 19, 3);
+        assert_eq!(state.visible_address_range(), Some(4..0x10));
+        render(&mut state, &data[..0x20], 19, 3);
+        assert_eq!(state.visible_address_range(), Some(0x14..0x20));
+        render(&mut state, &data[..0x22], 19, 3);
+        assert_eq!(state.visible_address_range(), Some(0x18..0x22));
+    }
+
+    #[test]
+    fn follow_only_when_grown() {
+        let data: Vec<u8> = (0..=0xff).collect();
+        let mut state = State::new();
+        state.set_follow(true);
+        render(&mut state, &data[..0x20], 19, 3);
+        state.offset_address = 0;
+        render(&mut state, &data[..0x20], 19, 3);
+        assert_eq!(state.get_offset_address(), 0);
+    }
+
+    #[test]
+    fn follow_disabled_by_scrolling_up() {
+        let data: Vec<u8> = (0..=0xff).collect();
+        let mut state = State::new();
+        state.set_follow(true);
+        render(&mut state, &data[..0x20], 19, 3);
+        state.scroll_up(1);
+        assert!(!state.is_following());
+        render(&mut state, &data[..0x40], 19, 3);
+        assert_eq!(state.visible_address_range(), Some(0x10..0x1c));
+    }
+
+    #[test]
+    fn visible_byte_count() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let mut state = State::new();
+        assert_eq!(state.visible_byte_count(), 0);
+        render(&mut state, &data, 19, 3);
+        assert_eq!(state.visible_byte_count(), 12);
+        state.scroll_down(10);
+        render(&mut state, &data, 19, 3);
+        assert_eq!(state.visible_byte_count(), 11);
+        state.set_offset_line(0);
+        render(&mut state, &data, 19, 10);
+        assert_eq!(state.visible_byte_count(), 0x13);
+    }
+
+    #[test]
+    fn page_down_and_up() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let mut state = State::new();
+        render(&mut state, &data, 19, 5);
+        state.select_address(Some(1));
+        assert_eq!(state.page_down(), 0x15);
+        render(&mut state, &data, 19, 5);
+        assert_eq!(state.visible_address_range(), Some(0x4..0x18));
+        assert_eq!(state.page_down(), 0x29);
+        assert_eq!(state.page_down(), 0x3d);
+        assert_eq!(state.page_down(), 0x3f);
+        assert_eq!(state.page_up(), 0x2b);
+        assert_eq!(state.selected_address(), Some(0x2b));
+    }
+
+    #[test]
+    fn page_down_without_selection() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let mut state = State::new();
+        render(&mut state, &data, 19, 5);
+        state.scroll_down(2);
+        assert_eq!(state.page_down(), 8);
+    }
+
+    #[test]
+    fn half_page_down_and_up() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let mut state = State::new();
+        render(&mut state, &data, 19, 5);
+        state.select_address(Some(1));
+        assert_eq!(state.half_page_down(), 9);
+        assert_eq!(state.half_page_down(), 0x11);
+        assert_eq!(state.half_page_up(), 9);
+        state.select_address(Some(0x3a));
+        assert_eq!(state.half_page_down(), 0x3f);
+        state.select_address(Some(3));
+        assert_eq!(state.half_page_up(), 0);
+    }
+
+    #[test]
+    fn half_page_at_least_a_row() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let mut state = State::new();
+        render(&mut state, &data, 19, 1);
+        state.select_address(Some(1));
+        assert_eq!(state.half_page_down(), 5);
+    }
+
+    #[test]
+    fn half_page_down_without_selection() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let mut state = State::new();
+        render(&mut state, &data, 19, 5);
+        state.scroll_down(2);
+        assert_eq!(state.half_page_down(), 8);
+    }
+
+    #[test]
+    fn select_first_and_last() {
+        let mut state = State::new();
+        assert!(state.select_last(0x20));
+        assert_eq!(state.selected_address(), Some(0x1f));
+        assert!(!state.select_last(0x20));
+        assert!(state.select_first());
+        assert_eq!(state.selected_address(), Some(0));
+        assert!(!state.select_first());
+        assert!(!state.select_last(0));
+        assert_eq!(state.selected_address(), Some(0));
+    }
+
+    #[test]
+    fn bookmarks_sorted_and_deduplicated() {
+        let mut state = State::new();
+        assert!(state.add_bookmark(8));
+        assert!(state.add_bookmark(2));
+        assert!(!state.add_bookmark(8));
+        assert!(state.add_bookmark(5));
+        assert_eq!(state.bookmarks(), [2, 5, 8]);
+        assert!(state.remove_bookmark(5));
+        assert!(!state.remove_bookmark(5));
+        assert_eq!(state.bookmarks(), [2, 8]);
+    }
+
+    #[test]
+    fn next_bookmark_wraps() {
+        let mut state = State::new();
+        assert!(!state.next_bookmark());
+        state.add_bookmark(2);
+        state.add_bookmark(5);
+        state.add_bookmark(8);
+        assert!(state.next_bookmark());
+        assert_eq!(state.selected_address(), Some(2));
+        state.select_address(Some(3));
+        assert!(state.next_bookmark());
+        assert_eq!(state.selected_address(), Some(5));
+        assert!(state.next_bookmark());
+        assert_eq!(state.selected_address(), Some(8));
+        assert!(state.next_bookmark());
+        assert_eq!(state.selected_address(), Some(2));
+    }
+
+    #[test]
+    fn prev_bookmark_wraps() {
+        let mut state = State::new();
+        assert!(!state.prev_bookmark());
+        state.add_bookmark(2);
+        state.add_bookmark(5);
+        state.add_bookmark(8);
+        assert!(state.prev_bookmark());
+        assert_eq!(state.selected_address(), Some(8));
+        state.select_address(Some(7));
+        assert!(state.prev_bookmark());
+        assert_eq!(state.selected_address(), Some(5));
+        assert!(state.prev_bookmark());
+        assert_eq!(state.selected_address(), Some(2));
+        assert!(state.prev_bookmark());
+        assert_eq!(state.selected_address(), Some(8));
+    }
+
+    #[test]
+    fn select_next_and_prev_printable() {
+        let data = b"\x00A\x01\x02 B\xffC";
+        let mut state = State::new();
+        assert!(state.select_next_printable(data));
+        assert_eq!(state.selected_address(), Some(1));
+        assert!(state.ensure_selected_in_view_on_next_render);
+        assert!(state.select_next_printable(data));
+        assert_eq!(state.selected_address(), Some(5));
+        assert!(state.select_next_printable(data));
+        assert_eq!(state.selected_address(), Some(7));
+        assert!(!state.select_next_printable(data));
+        assert_eq!(state.selected_address(), Some(7));
+        assert!(state.select_prev_printable(data));
+        assert_eq!(state.selected_address(), Some(5));
+        assert!(state.select_prev_printable(data));
+        assert_eq!(state.selected_address(), Some(1));
+        assert!(!state.select_prev_printable(data));
+        assert_eq!(state.selected_address(), Some(1));
+    }
+
+    #[test]
+    fn select_prev_printable_without_selection() {
+        let mut state = State::new();
+        assert!(state.select_prev_printable(b"ab\x00"));
+        assert_eq!(state.selected_address(), Some(1));
+    }
+
+    #[test]
+    fn select_printable_without_printable() {
+        let data = b"\x00 \n\xff";
+        let mut state = State::new();
+        assert!(!state.select_next_printable(data));
+        assert!(!state.select_prev_printable(data));
+        assert_eq!(state.selected_address(), None);
+        state.select_address(Some(1));
+        assert!(!state.select_next_printable(data));
+        assert_eq!(state.selected_address(), Some(1));
+    }
+
+    #[test]
+    fn single_bookmark_does_not_change() {
+        let mut state = State::new();
+        state.add_bookmark(2);
+        assert!(state.next_bookmark());
+        assert!(!state.next_bookmark());
+        assert!(!state.prev_bookmark());
+    }
+
+    #[test]
+    fn toggle_selected() {
+        let mut state = State::new();
+        assert!(state.toggle_selected(5));
+        assert!(state.toggle_selected(1));
+        assert!(state.toggle_selected(9));
+        assert!(!state.toggle_selected(5));
+        assert_eq!(
+            state.selection_set().iter().copied().collect::<Vec<_>>(),
+            [1, 9]
+        );
+        assert_eq!(state.selected_address(), None);
+
+        assert!(state.clear_selection_set());
+        assert!(!state.clear_selection_set());
+        assert!(state.selection_set().is_empty());
+    }
+
+    #[test]
+    fn copy_selection_set() {
+        let data: Vec<u8> = (0x40..0x50).collect();
+        let mut state = State::new();
+        assert!(state.copy_selection_set(&data).is_empty());
+        state.toggle_selected(7);
+        state.toggle_selected(2);
+        state.toggle_selected(0x20);
+        assert_eq!(state.copy_selection_set(&data), [0x42, 0x47]);
+    }
+
+    #[test]
+    fn selection_set_is_highlighted() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let mut state = State::new();
+        state.select_address(Some(0));
+        state.toggle_selected(2);
+        state.toggle_selected(9);
+        let area = Rect::new(0, 0, 19, 3);
+        let mut buffer = Buffer::empty(area);
+        let highlight = Style::new().fg(Color::Green);
+        let widget = BinaryDataWidget::new(&data).highlight_style(highlight);
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+        let highlighted = (0..3)
+            .flat_map(|y| (14..18).map(move |x| (x, y)))
+            .map(|(x, y)| buffer.get(x, y).style().fg == highlight.fg);
+        for (address, is_highlighted) in highlighted.enumerate() {
+            assert_eq!(is_highlighted, [0, 2, 9].contains(&address), "{address}");
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let mut state = State::new();
+        state.add_bookmark(8);
+        state.add_bookmark(2);
+        state.select_address(Some(0x30));
+        render(&mut state, &data, 19, 5);
+
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(
+            json,
+            r#"{"bookmarks":[2,8],"follow":false,"nibble":"High","nibble_navigation":false,"offset_address":32,"offset_column":0,"selected_address":48,"selection_anchor":null,"selection_follows_scroll":false,"selection_set":[],"wrap_navigation":false}"#
+        );
+
+        let restored: State = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.bookmarks(), [2, 8]);
+        assert_eq!(restored.get_offset_address(), 0x20);
+        assert_eq!(restored.selected_address(), Some(0x30));
+        assert!(restored.last_render_positions.is_none());
+        assert!(!restored.ensure_selected_in_view_on_next_render);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_sorts_bookmarks() {
+        let json = r#"{"bookmarks":[8,2,8],"offset_address":0,"selected_address":null,"selection_anchor":null}"#;
+        let restored: State = serde_json::from_str(json).unwrap();
+        assert_eq!(restored.bookmarks(), [2, 8]);
+    }
+
+    #[test]
+    fn render_positions_cached() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let mut state = State::new();
+        render(&mut state, &data, 19, 5);
+        let first = state.last_render_positions.unwrap();
+        render(&mut state, &data, 19, 5);
+        assert_eq!(state.last_render_positions, Some(first));
+        assert_eq!(
+            RenderPositions::new(Rect::new(0, 0, 19, 5), data.len(), LayoutOptions::new()),
+            Some(first)
+        );
+
+        render(&mut state, &data[..0x11], 19, 5);
+        let changed = state.last_render_positions.unwrap();
+        assert_ne!(changed, first);
+        assert_eq!(changed.available_data_lines, 5);
+
+        render(&mut state, &data, 50, 5);
+        assert_eq!(state.last_render_positions.unwrap().per_row, 8);
+    }
+
+    #[test]
+    fn drag_across_rows() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let mut state = State::new();
+        render(&mut state, &data, 19, 5);
+        assert!(state.select_at(4, 0));
+        assert_eq!(state.selected_range(), None);
+        assert!(state.drag_to(9, 1));
+        assert_eq!(state.selected_address(), Some(6));
+        assert_eq!(state.selected_range(), Some(0..=6));
+        assert!(state.drag_to(6, 1));
+        assert_eq!(state.selected_range(), Some(0..=5));
+    }
+
+    #[test]
+    fn select_at_known_cell() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let mut state = State::new();
+        assert!(!state.select_at(11, 2));
+        render(&mut state, &data, 19, 5);
+        assert!(state.select_at(11, 2));
+        assert_eq!(state.selected_address(), Some(11));
+        assert!(!state.select_at(11, 2));
+        assert!(state.select_at(15, 0));
+        assert_eq!(state.selected_address(), Some(1));
+    }
+
+    #[test]
+    fn scroll_horizontally() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let mut state = State::new();
+        let area = Rect::new(0, 0, 19, 5);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(&data).bytes_per_row(16);
+        StatefulWidget::render(widget.clone(), area, &mut buffer, &mut state);
+        assert!(!state.scroll_left(1));
+        assert!(state.scroll_right(4));
+        assert_eq!(state.clicked_address(4, 1), Some(0x14));
+        assert!(state.scroll_right(100));
+        assert_eq!(state.get_offset_column(), 12);
+        assert!(!state.scroll_right(1));
+
+        state.select_address(Some(0x12));
+        StatefulWidget::render(widget.clone(), area, &mut buffer, &mut state);
+        assert_eq!(state.get_offset_column(), 2);
+
+        // Column 6 is not visible when scrolled by the odd amount of 3 because of the cut off group
+        state.select_address(Some(0x16));
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+        assert_eq!(state.get_offset_column(), 4);
+    }
+
+    #[test]
+    fn cell_rect() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let mut state = State::new();
+        assert_eq!(state.cell_rect(0), None);
+        render(&mut state, &data, 19, 5);
+        assert_eq!(state.cell_rect(0), Some(Rect::new(4, 0, 2, 1)));
+        assert_eq!(state.cell_rect(6), Some(Rect::new(9, 1, 2, 1)));
+        assert_eq!(state.cell_rect(0x14), None);
+
+        state.scroll_down(2);
+        render(&mut state, &data, 19, 5);
+        assert_eq!(state.cell_rect(6), None);
+        assert_eq!(state.cell_rect(0x14), Some(Rect::new(4, 3, 2, 1)));
+    }
+
+    #[test]
+    fn reversed_groups_click_and_cell_rect() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let mut state = State::new();
+        let area = Rect::new(0, 0, 19, 5);
+        let mut buffer = Buffer::empty(area);
+        let widget = BinaryDataWidget::new(&data).byte_order(ByteOrder::ReversedGroups);
+        StatefulWidget::render(widget, area, &mut buffer, &mut state);
+        // Row 1 is shown as " 4:  5 4  7 6"
+        assert_eq!(buffer.get(5, 1).symbol(), "5");
+        assert_eq!(state.clicked_address(5, 1), Some(5));
+        assert_eq!(state.clicked_address(7, 1), Some(4));
+        assert_eq!(state.clicked_address(10, 1), Some(7));
+        assert_eq!(state.clicked_address(14, 1), Some(4));
+        assert_eq!(state.cell_rect(4), Some(Rect::new(6, 1, 2, 1)));
+        assert_eq!(state.cell_rect(5), Some(Rect::new(4, 1, 2, 1)));
+        assert_eq!(state.cell_rect(7), Some(Rect::new(9, 1, 2, 1)));
+    }
+
+    #[test]
+    fn is_visible() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let mut state = State::new();
+        assert!(!state.is_visible(0));
+        render(&mut state, &data, 19, 5);
+        state.scroll_down(2);
+        render(&mut state, &data, 19, 5);
+        assert!(!state.is_visible(7));
+        assert!(state.is_visible(8));
+        assert!(state.is_visible(0x1b));
+        assert!(!state.is_visible(0x1c));
+    }
+
+    #[test]
+    fn wrap_navigation() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let mut state = State::new();
+        render(&mut state, &data, 19, 5);
+        state.select_address(Some(0));
+        assert!(!state.key_left());
+        state.set_wrap_navigation(true);
+        assert!(state.key_left());
+        assert_eq!(state.selected_address(), Some(0x12));
+        assert!(state.key_right());
+        assert_eq!(state.selected_address(), Some(0));
+
+        state.select_address(Some(1));
+        assert!(state.key_up());
+        assert_eq!(state.selected_address(), Some(0x11));
+        assert!(state.key_down());
+        assert_eq!(state.selected_address(), Some(1));
+
+        state.select_address(Some(3));
+        assert!(state.key_up());
+        assert_eq!(state.selected_address(), Some(0xf));
+        assert!(state.key_down());
+        assert_eq!(state.selected_address(), Some(3));
+    }
+
+    #[test]
+    fn selection_follows_scroll() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let mut state = State::new();
+        render(&mut state, &data, 19, 5);
+        state.select_address(Some(1));
+        state.scroll_down(3);
+        assert_eq!(state.selected_address(), Some(1));
+
+        state.scroll_up(3);
+        state.set_selection_follows_scroll(true);
+        state.scroll_down(3);
+        assert_eq!(state.get_offset_address(), 12);
+        assert_eq!(state.selected_address(), Some(13));
+
+        state.select_address(Some(0x1e));
+        state.scroll_up(3);
+        assert_eq!(state.get_offset_address(), 0);
+        assert_eq!(state.selected_address(), Some(0x12));
+    }
+
+    #[test]
+    fn layout_getters() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let mut state = State::new();
+        assert_eq!(state.bytes_per_row(), None);
+        assert_eq!(state.address_width(), None);
+        render(&mut state, &data, 50, 5);
+        assert_eq!(state.bytes_per_row(), Some(8));
+        assert_eq!(state.address_width(), Some(2));
+    }
+
+    #[test]
+    fn offset_line() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let mut state = State::new();
+        state.set_offset_line(2);
+        assert_eq!(state.get_offset_address(), 16);
+        assert_eq!(state.offset_line(), 2);
+
+        render(&mut state, &data, 19, 5);
+        assert_eq!(state.offset_line(), 4);
+        state.set_offset_line(7);
+        assert_eq!(state.get_offset_address(), 28);
+        state.set_offset_line(1);
+        assert_eq!(state.get_offset_address(), 4);
+        render(&mut state, &data, 19, 5);
+        assert_eq!(state.offset_line(), 1);
+    }
+
+    #[test]
+    fn nav_outcome_at_boundaries() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let mut state = State::new();
+        render(&mut state, &data, 19, 5);
+        assert_eq!(state.key_left_outcome(), NavOutcome::Moved);
+        assert_eq!(state.selected_address(), Some(0x12));
+        assert_eq!(state.key_right_outcome(), NavOutcome::EndReached);
+        assert_eq!(state.key_down_outcome(), NavOutcome::EndReached);
+
+        state.select_address(Some(1));
+        assert_eq!(state.key_up_outcome(), NavOutcome::Moved);
+        assert_eq!(state.key_up_outcome(), NavOutcome::StartReached);
+        assert_eq!(state.key_left_outcome(), NavOutcome::StartReached);
+
+        assert_eq!(state.scroll_up_outcome(1), NavOutcome::StartReached);
+        assert_eq!(state.scroll_down_outcome(1), NavOutcome::EndReached);
+        render(&mut state, &data, 19, 3);
+        assert_eq!(state.scroll_down_outcome(1), NavOutcome::Scrolled);
+        assert!(NavOutcome::Scrolled.changed());
+        assert!(!NavOutcome::EndReached.changed());
+    }
+
+    #[test]
+    fn nibble_rollover() {
+        let data: Vec<u8> = (0..=0x12).collect();
+        let mut state = State::new();
+        render(&mut state, &data, 19, 5);
+        state.set_nibble_navigation(true);
+        state.select_address(Some(0));
+        assert!(state.key_right());
+        assert_eq!(state.selected_address(), Some(0));
+        assert_eq!(state.selected_nibble(), Nibble::Low);
+        assert!(state.key_right());
+        assert_eq!(state.selected_address(), Some(1));
+        assert_eq!(state.selected_nibble(), Nibble::High);
+        assert!(state.key_left());
+        assert_eq!(state.selected_address(), Some(0));
+        assert_eq!(state.selected_nibble(), Nibble::Low);
+        assert!(state.key_left());
+        assert_eq!(state.selected_nibble(), Nibble::High);
+        assert!(!state.key_left());
+        assert_eq!(state.selected_address(), Some(0));
+
+        state.select_address(Some(0x12));
+        assert!(state.key_right());
+        assert_eq!(state.selected_nibble(), Nibble::Low);
+        assert!(!state.key_right());
+        assert_eq!(state.selected_address(), Some(0x12));
+        assert_eq!(state.selected_nibble(), Nibble::Low);
+    }
+
+    #[test]
+    fn checksum_invalidated_after_edit_in_place() {
+        let mut data = b"123456789".to_vec();
+        let mut state = State::new();
+        assert_eq!(state.checksum(&data), 0xcbf4_3926);
+
+        data[0] = b'0';
+        assert_eq!(state.checksum(&data), 0xcbf4_3926);
+        state.invalidate_checksum();
+        assert_eq!(state.checksum(&data), crc32(&data));
+        assert_ne!(state.checksum(&data), 0xcbf4_3926);
+    }
+
+    #[test]
+    fn checksum_cached_without_slice() {
+        struct Lazy(Vec<u8>);
+        impl DataSource for Lazy {
+            fn len(&self) -> usize {
+                self.0.len()
+            }
+
+            fn byte(&self, address: usize) -> Option<u8> {
+                self.0.get(address).copied()
+            }
+        }
+
+        let mut data = Lazy(b"123456789".to_vec());
+        let mut state = State::new();
+        assert_eq!(state.checksum(&data), 0xcbf4_3926);
+        assert_eq!(state.checksum_cache.unwrap().0, None);
+
+        data.0[0] = b'0';
+        assert_eq!(state.checksum(&data), 0xcbf4_3926);
+        state.invalidate_checksum();
+        assert_eq!(state.checksum(&data), crc32(&data.0));
+
+        data.0.push(b'0');
+        assert_eq!(state.checksum(&data), crc32(&data.0));
+    }
+
+    #[test]
+    fn checksum_cached() {
+        let data = b"123456789".to_vec();
+        let mut state = State::new();
+        assert_eq!(state.checksum(&data), 0xcbf4_3926);
+        let (location, length, _) = state.checksum_cache.unwrap();
+        assert_eq!((location, length), (Some(data.as_ptr().addr()), 9));
+
+        state.checksum_cache = Some((location, length, 42));
+        assert_eq!(state.checksum(&data), 42);
+        assert_eq!(state.checksum(&data[..8]), crc32(&data[..8]));
+    }
+
+    #[test]
+    fn scroll_to_selection() {
+        let data: Vec<u8> = (0..=0x3f).collect();
+        let mut state = State::new();
+        state.select_address(Some(0x30));
+        render(&mut state, &data, 19, 5);
+        assert!(!state.ensure_selected_in_view_on_next_render);
+        state.set_offset_line(0);
+        render(&mut state, &data, 19, 5);
+        assert_eq!(state.get_offset_address(), 0);
+
+        state.scroll_to_selection();
+        assert!(state.ensure_selected_in_view_on_next_render);
+        assert_eq!(state.selected_address(), Some(0x30));
+        render(&mut state, &data, 19, 5);
+        assert!(state.is_visible(0x30));
+        assert_eq!(state.selected_address(), Some(0x30));
     }
 }
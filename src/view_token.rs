@@ -0,0 +1,11 @@
+/// Scroll position and selection of a [`BinaryDataWidgetState`](crate::BinaryDataWidgetState).
+///
+/// Created by [`bookmark_view`](crate::BinaryDataWidgetState::bookmark_view) and applied with [`restore_view`](crate::BinaryDataWidgetState::restore_view).
+/// This is lighter than persisting the whole state, for example to link into a view.
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ViewToken {
+    pub(crate) offset_address: usize,
+    pub(crate) selected_address: Option<usize>,
+}